@@ -0,0 +1,254 @@
+// Derive macros of nicole, re-exported by nicole under the `derive` feature.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Expr, Fields, Type};
+
+const PLAIN_INTEGERS: [&str; 5] = ["u8", "u16", "u32", "u64", "usize"];
+
+// Implements `IdLike` for a newtype over an integer, e.g. `struct NodeId(u32)`, along with every trait
+// `IdLike` needs (`Copy`, `Eq`, `Ord`), `Hash`, `Debug` and `Display` (which print the index). Index
+// conversions are delegated to the backing integer, which must itself be `IdLike`, e.g. `Option<NonMaxU32>`
+// for a niche-packed id.
+//
+// `#[id_like(null = ..)]` sets the null value; by default, it is the null value of the backing integer.
+// A custom null needs a plain unsigned integer field, and is skipped by the index conversions: indices
+// below it are stored as is, and the others shifted up by one, so that no index maps to null. When the
+// field type is an alias of such an integer, `#[id_like(width = ..)]` names the integer.
+///
+/// ```
+/// use nicole::IdLike;
+///
+/// #[derive(IdLike)]
+/// #[id_like(null = 0)]
+/// struct NodeId(u8);
+///
+/// assert_eq!(NodeId::from_index(0), NodeId(1));
+/// assert_eq!(NodeId::from_index(254), NodeId(255));
+/// assert_eq!(NodeId::try_from_index(255), None);
+/// assert_eq!(NodeId(255).to_index(), 254);
+/// assert!(NodeId(0).is_null());
+///
+/// #[derive(IdLike)]
+/// #[id_like(null = 3)]
+/// struct EdgeId(u8);
+///
+/// assert_eq!(EdgeId::from_index(2), EdgeId(2));
+/// assert_eq!(EdgeId::from_index(3), EdgeId(4));
+/// assert_eq!(EdgeId(4).to_index(), 3);
+/// assert!((0..255).all(|i| !EdgeId::from_index(i).is_null()));
+/// ```
+///
+/// An alias of a plain integer works with `width`:
+///
+/// ```
+/// use nicole::IdLike;
+///
+/// type Raw = u32;
+///
+/// #[derive(IdLike)]
+/// #[id_like(null = 0, width = u32)]
+/// struct NodeId(Raw);
+///
+/// assert_eq!(NodeId::from_index(0), NodeId(1));
+/// assert!(NodeId(0).is_null());
+/// assert_eq!(format!("{:?} {}", NodeId(0), NodeId(5)), "NodeId(null) 4");
+/// ```
+///
+/// A custom null needs a plain integer field:
+///
+/// ```compile_fail
+/// use nicole::{IdLike, NonMaxU32};
+///
+/// #[derive(IdLike)]
+/// #[id_like(null = NonMaxU32::new(0).unwrap())]
+/// struct NodeId(NonMaxU32);
+/// ```
+///
+/// Or a `width` naming one:
+///
+/// ```compile_fail
+/// use nicole::IdLike;
+///
+/// type Raw = u32;
+///
+/// #[derive(IdLike)]
+/// #[id_like(null = 0)]
+/// struct NodeId(Raw);
+/// ```
+#[proc_macro_derive(IdLike, attributes(id_like))]
+pub fn derive_id_like(input: TokenStream) -> TokenStream
+{
+    let input = parse_macro_input!(input as DeriveInput);
+    id_like(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn id_like(input: DeriveInput) -> Result<TokenStream2, Error>
+{
+    let name = &input.ident;
+
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(&input.generics, "IdLike cannot be derived for generic types"));
+    }
+
+    let field_type = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
+            _ => return Err(Error::new_spanned(&input, "IdLike can only be derived for newtypes, e.g. `struct Id(u32)`"))
+        },
+        _ => return Err(Error::new_spanned(&input, "IdLike can only be derived for structs"))
+    };
+
+    let mut null: Option<Expr> = None;
+    let mut width: Option<Type> = None;
+
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("id_like")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("null") {
+                null = Some(meta.value()?.parse()?);
+                Ok(())
+            }
+            else if meta.path.is_ident("width") {
+                width = Some(meta.value()?.parse()?);
+                Ok(())
+            }
+            else {
+                Err(meta.error("expected `null` or `width`"))
+            }
+        })?;
+    }
+
+    if let Some(width) = &width {
+        if !is_plain_integer(width) {
+            return Err(Error::new_spanned(width, "`width` must be u8, u16, u32, u64 or usize"));
+        }
+    }
+
+    let id_like = match null {
+        Some(null) => {
+            let int_type = width.as_ref().unwrap_or(field_type);
+            if !is_plain_integer(int_type) {
+                return Err(Error::new_spanned(
+                    field_type,
+                    "a custom `null` needs a field of type u8, u16, u32, u64 or usize, or `#[id_like(width = ..)]` naming it"
+                ));
+            }
+            shifted_id_like(name, int_type, &null)
+        },
+        None => delegated_id_like(name, field_type)
+    };
+    let name_str = name.to_string();
+
+    Ok(quote! {
+        impl ::core::clone::Clone for #name
+        {
+            fn clone(&self) -> Self { *self }
+        }
+
+        impl ::core::marker::Copy for #name {}
+
+        impl ::core::cmp::PartialEq for #name
+        {
+            fn eq(&self, other: &Self) -> bool { self.0 == other.0 }
+        }
+
+        impl ::core::cmp::Eq for #name {}
+
+        impl ::core::cmp::PartialOrd for #name
+        {
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> { ::core::option::Option::Some(self.0.cmp(&other.0)) }
+        }
+
+        impl ::core::cmp::Ord for #name
+        {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering { self.0.cmp(&other.0) }
+        }
+
+        impl ::core::hash::Hash for #name
+        {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) { self.0.hash(state) }
+        }
+
+        impl ::core::fmt::Debug for #name
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result
+            {
+                if ::nicole::IdLike::is_null(*self) {
+                    ::core::write!(f, "{}(null)", #name_str)
+                }
+                else {
+                    ::core::write!(f, "{}({})", #name_str, ::nicole::IdLike::to_index(*self))
+                }
+            }
+        }
+
+        impl ::core::fmt::Display for #name
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result
+            {
+                if ::nicole::IdLike::is_null(*self) {
+                    ::core::write!(f, "null")
+                }
+                else {
+                    ::core::write!(f, "{}", ::nicole::IdLike::to_index(*self))
+                }
+            }
+        }
+
+        #id_like
+    })
+}
+
+fn is_plain_integer(ty: &Type) -> bool
+{
+    match ty {
+        Type::Path(path) => path.qself.is_none() && PLAIN_INTEGERS.iter().any(|int| path.path.is_ident(int)),
+        _ => false
+    }
+}
+
+fn delegated_id_like(name: &syn::Ident, field_type: &Type) -> TokenStream2
+{
+    quote! {
+        impl ::nicole::IdLike for #name
+        {
+            fn null() -> Self { #name(<#field_type as ::nicole::IdLike>::null()) }
+            fn to_index(self) -> usize { <#field_type as ::nicole::IdLike>::to_index(self.0) }
+
+            fn try_from_index(index: usize) -> ::core::option::Option<Self>
+            {
+                <#field_type as ::nicole::IdLike>::try_from_index(index).map(#name)
+            }
+        }
+    }
+}
+
+// Values above null store the index below them; null itself maps to `usize::MAX`, like `Option` ids.
+// `int_type` is the field type, or the integer it aliases.
+fn shifted_id_like(name: &syn::Ident, int_type: &Type, null: &Expr) -> TokenStream2
+{
+    quote! {
+        impl ::nicole::IdLike for #name
+        {
+            fn null() -> Self { #name(#null) }
+
+            fn to_index(self) -> usize
+            {
+                let null: #int_type = #null;
+                match self.0 {
+                    x if x == null => usize::MAX,
+                    x if x > null => <usize as ::core::convert::TryFrom<#int_type>>::try_from(x - 1).expect("Id out of range for nicole::IdLike"),
+                    x => <usize as ::core::convert::TryFrom<#int_type>>::try_from(x).expect("Id out of range for nicole::IdLike")
+                }
+            }
+
+            fn try_from_index(index: usize) -> ::core::option::Option<Self>
+            {
+                let null: #int_type = #null;
+                let raw = <#int_type as ::core::convert::TryFrom<usize>>::try_from(index).ok()?;
+                if raw < null { ::core::option::Option::Some(#name(raw)) } else { raw.checked_add(1).map(#name) }
+            }
+        }
+    }
+}
@@ -0,0 +1,268 @@
+// Generic algorithms over the index traits, in the spirit of the C++ <algorithm> header. Ranges are
+// half-open, given as `first` and `last` indices of the collection, and results are indices (with `last`
+// meaning "not found") rather than references.
+
+use std::cmp::Ordering;
+
+use crate::index::{ForwardIndex, BidirectionalIndex, RandomAccessIndex, SwapIndex};
+
+pub fn find_if<C, I, P>(collection: &C, mut first: I, last: I, mut pred: P) -> I
+    where
+        C: ForwardIndex<I> + ?Sized,
+        I: Copy + PartialEq,
+        P: FnMut(&C::Output) -> bool
+{
+    while first != last && !pred(&collection[first]) {
+        collection.increment(&mut first);
+    }
+    first
+}
+
+pub fn count<C, I>(collection: &C, first: I, last: I, value: &C::Output) -> usize
+    where
+        C: ForwardIndex<I> + ?Sized,
+        C::Output: PartialEq,
+        I: Copy + PartialEq
+{
+    count_if(collection, first, last, |x| x == value)
+}
+
+pub fn count_if<C, I, P>(collection: &C, mut first: I, last: I, mut pred: P) -> usize
+    where
+        C: ForwardIndex<I> + ?Sized,
+        I: Copy + PartialEq,
+        P: FnMut(&C::Output) -> bool
+{
+    let mut ret = 0;
+    while first != last {
+        if pred(&collection[first]) {
+            ret += 1;
+        }
+        collection.increment(&mut first);
+    }
+    ret
+}
+
+pub fn distance<C, I>(collection: &C, mut first: I, last: I) -> usize
+    where
+        C: ForwardIndex<I> + ?Sized,
+        I: Copy + PartialEq
+{
+    let mut ret = 0;
+    while first != last {
+        collection.increment(&mut first);
+        ret += 1;
+    }
+    ret
+}
+
+// First index whose element is not less than `value`, in a sorted range. Performs O(log n) comparisons,
+// but O(n) increments unless the collection overrides `move_forward`; see `binary_search` for random
+// access collections.
+pub fn lower_bound<C, I>(collection: &C, mut first: I, last: I, value: &C::Output) -> I
+    where
+        C: ForwardIndex<I> + ?Sized,
+        C::Output: Ord,
+        I: Copy + PartialEq
+{
+    let mut len = distance(collection, first, last);
+    while len > 0 {
+        let half = len / 2;
+        let mut middle = first;
+        collection.move_forward(&mut middle, half);
+
+        if collection[middle] < *value {
+            first = collection.next(middle);
+            len -= half + 1;
+        }
+        else {
+            len = half;
+        }
+    }
+    first
+}
+
+// Searches a sorted range in O(log n). Like `slice::binary_search`, returns `Ok` with the index of a
+// matching element, or `Err` with the index where `value` would be inserted.
+pub fn binary_search<C, I>(collection: &C, mut first: I, last: I, value: &C::Output) -> Result<I, I>
+    where
+        C: RandomAccessIndex<I> + ?Sized,
+        C::Output: Ord,
+        I: Copy + PartialEq
+{
+    let mut len = collection.distance(&first, &last);
+    while len > 0 {
+        let half = len / 2;
+        let mut middle = first;
+        collection.jump(&mut middle, half);
+
+        match collection[middle].cmp(value) {
+            Ordering::Less => {
+                first = middle;
+                collection.jump(&mut first, 1);
+                len -= half + 1;
+            },
+            Ordering::Equal => return Ok(middle),
+            Ordering::Greater => len = half
+        }
+    }
+    Err(first)
+}
+
+pub fn reverse<C, I>(collection: &mut C, mut first: I, mut last: I)
+    where
+        C: BidirectionalIndex<I> + SwapIndex<I> + ?Sized,
+        I: Copy + PartialEq
+{
+    while first != last {
+        collection.decrement(&mut last);
+        if first == last {
+            break;
+        }
+        collection.swap_indices(first, last);
+        collection.increment(&mut first);
+    }
+}
+
+// Rotates the range so that `middle` becomes its first element. Returns the new index of the element
+// that was at `first`.
+pub fn rotate<C, I>(collection: &mut C, mut first: I, mut middle: I, last: I) -> I
+    where
+        C: ForwardIndex<I> + SwapIndex<I> + ?Sized,
+        I: Copy + PartialEq
+{
+    if first == middle {
+        return last;
+    }
+    if middle == last {
+        return first;
+    }
+
+    let mut next = middle;
+    loop {
+        collection.swap_indices(first, next);
+        collection.increment(&mut first);
+        collection.increment(&mut next);
+        if first == middle {
+            middle = next;
+        }
+        if next == last {
+            break;
+        }
+    }
+
+    let ret = first;
+
+    next = middle;
+    while next != last {
+        collection.swap_indices(first, next);
+        collection.increment(&mut first);
+        collection.increment(&mut next);
+        if first == middle {
+            middle = next;
+        }
+        else if next == last {
+            next = middle;
+        }
+    }
+
+    ret
+}
+
+// Moves the elements satisfying `pred` before the rest (not stable). Returns the first index of the
+// second group.
+pub fn partition<C, I, P>(collection: &mut C, first: I, last: I, mut pred: P) -> I
+    where
+        C: ForwardIndex<I> + SwapIndex<I> + ?Sized,
+        I: Copy + PartialEq,
+        P: FnMut(&C::Output) -> bool
+{
+    let mut first = find_if(collection, first, last, |x| !pred(x));
+    if first == last {
+        return first;
+    }
+
+    let mut i = collection.next(first);
+    while i != last {
+        if pred(&collection[i]) {
+            collection.swap_indices(i, first);
+            collection.increment(&mut first);
+        }
+        collection.increment(&mut i);
+    }
+    first
+}
+
+pub fn is_sorted<C, I>(collection: &C, first: I, last: I) -> bool
+    where
+        C: ForwardIndex<I> + ?Sized,
+        C::Output: PartialOrd,
+        I: Copy + PartialEq
+{
+    if first == last {
+        return true;
+    }
+
+    let mut prev = first;
+    let mut curr = collection.next(first);
+    while curr != last {
+        if collection[curr] < collection[prev] {
+            return false;
+        }
+        prev = curr;
+        collection.increment(&mut curr);
+    }
+    true
+}
+
+// First index whose element equals the next one, or `last`.
+pub fn adjacent_find<C, I>(collection: &C, first: I, last: I) -> I
+    where
+        C: ForwardIndex<I> + ?Sized,
+        C::Output: PartialEq,
+        I: Copy + PartialEq
+{
+    if first == last {
+        return last;
+    }
+
+    let mut prev = first;
+    let mut curr = collection.next(first);
+    while curr != last {
+        if collection[prev] == collection[curr] {
+            return prev;
+        }
+        prev = curr;
+        collection.increment(&mut curr);
+    }
+    last
+}
+
+// First index where the range [first2, last2) of `needle` occurs in [first, last), or `last`.
+pub fn search<C, I, N, J>(collection: &C, mut first: I, last: I, needle: &N, first2: J, last2: J) -> I
+    where
+        C: ForwardIndex<I> + ?Sized,
+        N: ForwardIndex<J> + ?Sized,
+        C::Output: PartialEq<N::Output>,
+        I: Copy + PartialEq,
+        J: Copy + PartialEq
+{
+    loop {
+        let mut i = first;
+        let mut j = first2;
+        loop {
+            if j == last2 {
+                return first;
+            }
+            if i == last {
+                return last;
+            }
+            if collection[i] != needle[j] {
+                break;
+            }
+            collection.increment(&mut i);
+            needle.increment(&mut j);
+        }
+        collection.increment(&mut first);
+    }
+}
@@ -0,0 +1,338 @@
+use std::{
+    marker::PhantomData,
+    ops::{Index, IndexMut, Deref}
+};
+
+use uuid::Uuid;
+
+use crate::{
+    IdLike,
+    index::{IndexExt, ForwardIndex, BackwardIndex, SwapIndex},
+    typedvec::TypedVec,
+    veclist::{Link, VecList, VecListIndex}
+};
+
+// `fn(&'id ()) -> &'id ()` makes `'id` invariant, so two brands can never be unified by the compiler.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct Brand<'id>(PhantomData<fn(&'id ()) -> &'id ()>);
+
+// A collection whose indices carry a unique lifetime, created by `branded`. An index obtained from one
+// `Branded` collection does not compile when used with another.
+pub struct Branded<'id, C>
+{
+    inner: C,
+    brand: Brand<'id>
+}
+
+pub type BrandedVec<'id, T> = Branded<'id, Vec<T>>;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BrandedIndex<'id, I>
+{
+    index: I,
+    brand: Brand<'id>
+}
+
+impl<'id, I> BrandedIndex<'id, I>
+{
+    pub fn raw(self) -> I { self.index }
+}
+
+/// Runs `f` with `collection` branded by a fresh lifetime. Indices cannot escape `f`; use `Tagged`
+/// when they need to outlive it.
+///
+/// ```compile_fail
+/// use nicole::{branded, ForwardIndex};
+///
+/// branded(vec![1, 2], |a| branded(vec![3, 4], |b| {
+///     let index = a.begin();
+///     b[index]
+/// }));
+/// ```
+///
+/// ```compile_fail
+/// use nicole::branded;
+///
+/// branded(vec![1, 2], |mut a| *a = vec![3]);
+/// ```
+pub fn branded<C, F, R>(collection: C, f: F) -> R
+    where F: for<'id> FnOnce(Branded<'id, C>) -> R
+{
+    f(Branded { inner: collection, brand: Brand(PhantomData) })
+}
+
+impl<'id, C> Branded<'id, C>
+{
+    pub fn into_inner(self) -> C { self.inner }
+
+    fn wrap<I>(&self, index: I) -> BrandedIndex<'id, I>
+    {
+        BrandedIndex { index, brand: self.brand }
+    }
+}
+
+impl<'id, C> Deref for Branded<'id, C>
+{
+    type Target = C;
+
+    fn deref(&self) -> &Self::Target { &self.inner }
+}
+
+// There is no `DerefMut`: replacing the collection would keep its brand, and so its indices. Only the
+// mutations that keep the collection in place are forwarded, per collection.
+impl<'id, T> Branded<'id, Vec<T>>
+{
+    pub fn push(&mut self, value: T) { self.inner.push(value) }
+    pub fn pop(&mut self) -> Option<T> { self.inner.pop() }
+    pub fn truncate(&mut self, len: usize) { self.inner.truncate(len) }
+    pub fn clear(&mut self) { self.inner.clear() }
+    pub fn as_mut_slice(&mut self) -> &mut [T] { &mut self.inner }
+}
+
+impl<'id, K, V> Branded<'id, TypedVec<K, V>>
+    where K: IdLike
+{
+    pub fn push(&mut self, value: V) -> BrandedIndex<'id, K> { let key = self.inner.push(value); self.wrap(key) }
+    pub fn pop(&mut self) -> Option<V> { self.inner.pop() }
+    pub fn truncate(&mut self, len: usize) { self.inner.truncate(len) }
+    pub fn clear(&mut self) { self.inner.clear() }
+    pub fn as_mut_slice(&mut self) -> &mut [V] { &mut self.inner }
+}
+
+impl<'id, T, L> Branded<'id, VecList<T, L>>
+    where L: Link
+{
+    pub fn insert(&mut self, next: BrandedIndex<'id, VecListIndex<L>>, value: T) { self.inner.insert(next.index, value) }
+    pub fn remove(&mut self, index: BrandedIndex<'id, VecListIndex<L>>) -> T { self.inner.remove(index.index) }
+    pub fn push_back(&mut self, value: T) { self.inner.push_back(value) }
+    pub fn push_front(&mut self, value: T) { self.inner.push_front(value) }
+    pub fn pop_back(&mut self) -> T { self.inner.pop_back() }
+    pub fn pop_front(&mut self) -> T { self.inner.pop_front() }
+    pub fn clear(&mut self) { self.inner.clear() }
+    pub fn reverse(&mut self) { self.inner.reverse() }
+
+    pub fn sort_by<F>(&mut self, compare: F)
+        where F: FnMut(&T, &T) -> std::cmp::Ordering
+    {
+        self.inner.sort_by(compare)
+    }
+}
+
+impl<'id, C, I> Index<BrandedIndex<'id, I>> for Branded<'id, C>
+    where C: Index<I>
+{
+    type Output = C::Output;
+
+    fn index(&self, index: BrandedIndex<'id, I>) -> &Self::Output { &self.inner[index.index] }
+}
+
+impl<'id, C, I> IndexMut<BrandedIndex<'id, I>> for Branded<'id, C>
+    where C: IndexMut<I>
+{
+    fn index_mut(&mut self, index: BrandedIndex<'id, I>) -> &mut Self::Output { &mut self.inner[index.index] }
+}
+
+impl<'id, C, I> IndexExt<BrandedIndex<'id, I>> for Branded<'id, C>
+    where C: IndexExt<I>
+{
+    fn valid(&self, index: &BrandedIndex<'id, I>) -> bool { self.inner.valid(&index.index) }
+}
+
+impl<'id, C, I> SwapIndex<BrandedIndex<'id, I>> for Branded<'id, C>
+    where C: SwapIndex<I>
+{
+    fn swap_indices(&mut self, a: BrandedIndex<'id, I>, b: BrandedIndex<'id, I>) { self.inner.swap_indices(a.index, b.index) }
+}
+
+impl<'id, C, I> ForwardIndex<BrandedIndex<'id, I>> for Branded<'id, C>
+    where C: ForwardIndex<I>
+{
+    fn begin(&self) -> BrandedIndex<'id, I> { self.wrap(self.inner.begin()) }
+    fn past_end(&self) -> BrandedIndex<'id, I> { self.wrap(self.inner.past_end()) }
+    fn increment(&self, idx: &mut BrandedIndex<'id, I>) { self.inner.increment(&mut idx.index) }
+    fn move_forward(&self, idx: &mut BrandedIndex<'id, I>, distance: usize) { self.inner.move_forward(&mut idx.index, distance) }
+}
+
+impl<'id, C, I> BackwardIndex<BrandedIndex<'id, I>> for Branded<'id, C>
+    where C: BackwardIndex<I>
+{
+    fn rbegin(&self) -> BrandedIndex<'id, I> { self.wrap(self.inner.rbegin()) }
+    fn rend(&self) -> BrandedIndex<'id, I> { self.wrap(self.inner.rend()) }
+    fn decrement(&self, idx: &mut BrandedIndex<'id, I>) { self.inner.decrement(&mut idx.index) }
+    fn move_backward(&self, idx: &mut BrandedIndex<'id, I>, distance: usize) { self.inner.move_backward(&mut idx.index, distance) }
+}
+
+// Runtime fallback of `Branded`: indices carry the tag of their collection, and using them with
+// another collection panics.
+pub struct Tagged<C>
+{
+    inner: C,
+    tag: Uuid
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TaggedIndex<I>
+{
+    index: I,
+    tag: Uuid
+}
+
+impl<I> TaggedIndex<I>
+{
+    pub fn raw(self) -> I { self.index }
+}
+
+impl<C> Tagged<C>
+{
+    pub fn new(collection: C) -> Self
+    {
+        Self { inner: collection, tag: Uuid::new_v4() }
+    }
+
+    pub fn into_inner(self) -> C { self.inner }
+
+    pub fn owns<I>(&self, index: &TaggedIndex<I>) -> bool { index.tag == self.tag }
+
+    fn wrap<I>(&self, index: I) -> TaggedIndex<I>
+    {
+        TaggedIndex { index, tag: self.tag }
+    }
+
+    fn check<I>(&self, index: &TaggedIndex<I>)
+    {
+        assert!(self.owns(index), "Index from another collection on nicole::Tagged");
+    }
+}
+
+impl<C> Deref for Tagged<C>
+{
+    type Target = C;
+
+    fn deref(&self) -> &Self::Target { &self.inner }
+}
+
+// As for `Branded`, no `DerefMut`; the tag would survive a replaced collection.
+impl<T> Tagged<Vec<T>>
+{
+    pub fn push(&mut self, value: T) { self.inner.push(value) }
+    pub fn pop(&mut self) -> Option<T> { self.inner.pop() }
+    pub fn truncate(&mut self, len: usize) { self.inner.truncate(len) }
+    pub fn clear(&mut self) { self.inner.clear() }
+    pub fn as_mut_slice(&mut self) -> &mut [T] { &mut self.inner }
+}
+
+impl<K, V> Tagged<TypedVec<K, V>>
+    where K: IdLike
+{
+    pub fn push(&mut self, value: V) -> TaggedIndex<K> { let key = self.inner.push(value); self.wrap(key) }
+    pub fn pop(&mut self) -> Option<V> { self.inner.pop() }
+    pub fn truncate(&mut self, len: usize) { self.inner.truncate(len) }
+    pub fn clear(&mut self) { self.inner.clear() }
+    pub fn as_mut_slice(&mut self) -> &mut [V] { &mut self.inner }
+}
+
+impl<T, L> Tagged<VecList<T, L>>
+    where L: Link
+{
+    pub fn insert(&mut self, next: TaggedIndex<VecListIndex<L>>, value: T)
+    {
+        self.check(&next);
+        self.inner.insert(next.index, value)
+    }
+
+    pub fn remove(&mut self, index: TaggedIndex<VecListIndex<L>>) -> T
+    {
+        self.check(&index);
+        self.inner.remove(index.index)
+    }
+
+    pub fn push_back(&mut self, value: T) { self.inner.push_back(value) }
+    pub fn push_front(&mut self, value: T) { self.inner.push_front(value) }
+    pub fn pop_back(&mut self) -> T { self.inner.pop_back() }
+    pub fn pop_front(&mut self) -> T { self.inner.pop_front() }
+    pub fn clear(&mut self) { self.inner.clear() }
+    pub fn reverse(&mut self) { self.inner.reverse() }
+
+    pub fn sort_by<F>(&mut self, compare: F)
+        where F: FnMut(&T, &T) -> std::cmp::Ordering
+    {
+        self.inner.sort_by(compare)
+    }
+}
+
+impl<C, I> Index<TaggedIndex<I>> for Tagged<C>
+    where C: Index<I>
+{
+    type Output = C::Output;
+
+    fn index(&self, index: TaggedIndex<I>) -> &Self::Output
+    {
+        self.check(&index);
+        &self.inner[index.index]
+    }
+}
+
+impl<C, I> IndexMut<TaggedIndex<I>> for Tagged<C>
+    where C: IndexMut<I>
+{
+    fn index_mut(&mut self, index: TaggedIndex<I>) -> &mut Self::Output
+    {
+        self.check(&index);
+        &mut self.inner[index.index]
+    }
+}
+
+impl<C, I> IndexExt<TaggedIndex<I>> for Tagged<C>
+    where C: IndexExt<I>
+{
+    fn valid(&self, index: &TaggedIndex<I>) -> bool { self.owns(index) && self.inner.valid(&index.index) }
+}
+
+impl<C, I> SwapIndex<TaggedIndex<I>> for Tagged<C>
+    where C: SwapIndex<I>
+{
+    fn swap_indices(&mut self, a: TaggedIndex<I>, b: TaggedIndex<I>)
+    {
+        self.check(&a);
+        self.check(&b);
+        self.inner.swap_indices(a.index, b.index)
+    }
+}
+
+impl<C, I> ForwardIndex<TaggedIndex<I>> for Tagged<C>
+    where C: ForwardIndex<I>
+{
+    fn begin(&self) -> TaggedIndex<I> { self.wrap(self.inner.begin()) }
+    fn past_end(&self) -> TaggedIndex<I> { self.wrap(self.inner.past_end()) }
+
+    fn increment(&self, idx: &mut TaggedIndex<I>)
+    {
+        self.check(idx);
+        self.inner.increment(&mut idx.index)
+    }
+
+    fn move_forward(&self, idx: &mut TaggedIndex<I>, distance: usize)
+    {
+        self.check(idx);
+        self.inner.move_forward(&mut idx.index, distance)
+    }
+}
+
+impl<C, I> BackwardIndex<TaggedIndex<I>> for Tagged<C>
+    where C: BackwardIndex<I>
+{
+    fn rbegin(&self) -> TaggedIndex<I> { self.wrap(self.inner.rbegin()) }
+    fn rend(&self) -> TaggedIndex<I> { self.wrap(self.inner.rend()) }
+
+    fn decrement(&self, idx: &mut TaggedIndex<I>)
+    {
+        self.check(idx);
+        self.inner.decrement(&mut idx.index)
+    }
+
+    fn move_backward(&self, idx: &mut TaggedIndex<I>, distance: usize)
+    {
+        self.check(idx);
+        self.inner.move_backward(&mut idx.index, distance)
+    }
+}
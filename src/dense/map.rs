@@ -1,6 +1,6 @@
 use std::{ops::{Index, IndexMut}, fmt::Debug};
 
-use crate::Identifier;
+use crate::{IdLike, Identifier};
 
 #[derive(Default, Clone)]
 pub struct DenseMap<K, V> {
@@ -34,7 +34,7 @@ impl<K, V> DenseMap<K, V>
 
     pub fn contains_key(&self, key: &K) -> bool
     {
-        let pos: usize = key.id().into();
+        let pos = key.id().to_index();
 
         pos < self.indices.len() && self.indices[pos] != INVALID
     }
@@ -49,14 +49,14 @@ impl<K, V> DenseMap<K, V>
         }
         else {
             let ret = self.elements.swap_remove(elem_index);
-            self.indices[self.elements[elem_index].0.id().into()] = elem_index;
+            self.indices[self.elements[elem_index].0.id().to_index()] = elem_index;
             Some(ret)
         }
     }
 
     pub fn remove_entry(&mut self, key: &K) -> Option<(K, V)>
     {
-        let pos: usize = key.id().into();
+        let pos = key.id().to_index();
 
         if pos < self.indices.len() {
             let ret = self.remove_at(self.indices[pos]);
@@ -75,7 +75,7 @@ impl<K, V> DenseMap<K, V>
 
     pub fn insert(&mut self, key: K, value: V) -> Option<V>
     {
-        let pos: usize = key.id().into();
+        let pos = key.id().to_index();
 
         if pos >= self.indices.len() {
             self.indices.resize(pos + 1, INVALID);
@@ -88,22 +88,22 @@ impl<K, V> DenseMap<K, V>
         ret.map(|x| x.1)
     }
 
-    pub fn iter<'a>(&'a self) -> impl Iterator<Item = &(K, V)> + 'a
+    pub fn iter<'a>(&'a self) -> impl Iterator<Item = &'a (K, V)> + 'a
     {
         self.elements.iter()
     }
 
-    pub fn keys<'a>(&'a self) -> impl Iterator<Item = &K> + 'a
+    pub fn keys<'a>(&'a self) -> impl Iterator<Item = &'a K> + 'a
     {
         self.elements.iter().map(|x| &x.0)
     }
 
-    pub fn values<'a>(&'a self) -> impl Iterator<Item = &V> + 'a
+    pub fn values<'a>(&'a self) -> impl Iterator<Item = &'a V> + 'a
     {
         self.elements.iter().map(|x| &x.1)
     }
 
-    pub fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &mut (K, V)> + 'a
+    pub fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut (K, V)> + 'a
     {
         self.elements.iter_mut()
     }
@@ -116,7 +116,7 @@ impl<K, V> DenseMap<K, V>
     pub fn get(&self, key: &K) -> Option<&V>
     {
         if self.contains_key(key) {
-            Some(&self.elements[self.indices[key.id().into()]].1)
+            Some(&self.elements[self.indices[key.id().to_index()]].1)
         }
         else {
             None
@@ -126,7 +126,7 @@ impl<K, V> DenseMap<K, V>
     pub fn get_mut(&mut self, key: &K) -> Option<&mut V>
     {
         if self.contains_key(key) {
-            Some(&mut self.elements[self.indices[key.id().into()]].1)
+            Some(&mut self.elements[self.indices[key.id().to_index()]].1)
         }
         else {
             None
@@ -173,7 +173,8 @@ impl<'a, K, V> Entry<'a, K, V>
             self.parent.insert(self.key, value);
         }
 
-        &mut self.parent.elements[id.into()].1
+        let pos = self.parent.indices[id.to_index()];
+        &mut self.parent.elements[pos].1
     }
 
     pub fn or_insert_with<F>(self, f: F) -> &'a mut V
@@ -185,7 +186,8 @@ impl<'a, K, V> Entry<'a, K, V>
             self.parent.insert(self.key, f());
         }
 
-        &mut self.parent.elements[id.into()].1
+        let pos = self.parent.indices[id.to_index()];
+        &mut self.parent.elements[pos].1
     }
 }
 
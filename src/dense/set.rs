@@ -1,9 +1,10 @@
 use std::{
     iter::FromIterator,
-    fmt::Debug
+    fmt::Debug,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Sub, SubAssign}
 };
 
-use crate::identifier::Identifier;
+use crate::identifier::{IdLike, Identifier};
 
 #[derive(Default, Clone)]
 pub struct DenseSet<T> {
@@ -36,7 +37,7 @@ impl<T> DenseSet<T>
 
     pub fn contains(&self, value: &T) -> bool
     {
-        let pos: usize = value.id().into();
+        let pos = value.id().to_index();
 
         pos < self.indices.len() && self.indices[pos] != INVALID
     }
@@ -51,14 +52,14 @@ impl<T> DenseSet<T>
         }
         else {
             let ret = self.elements.swap_remove(elem_index);
-            self.indices[self.elements[elem_index].id().into()] = elem_index;
+            self.indices[self.elements[elem_index].id().to_index()] = elem_index;
             Some(ret)
         }
     }
 
     pub fn remove(&mut self, value: &T) -> Option<T>
     {
-        let pos: usize = value.id().into();
+        let pos = value.id().to_index();
 
         if pos < self.indices.len() {
             let ret = self.remove_at(self.indices[pos]);
@@ -72,7 +73,7 @@ impl<T> DenseSet<T>
 
     pub fn insert(&mut self, value: T) -> Option<T>
     {
-        let pos: usize = value.id().into();
+        let pos = value.id().to_index();
 
         if pos >= self.indices.len() {
             self.indices.resize(pos + 1, INVALID);
@@ -85,12 +86,131 @@ impl<T> DenseSet<T>
         ret
     }
 
-    pub fn iter<'a>(&'a self) -> impl Iterator<Item = &T> + 'a
+    pub fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T> + 'a
     {
         self.elements.iter()
     }
+
+    pub fn retain<F>(&mut self, mut f: F)
+        where F: FnMut(&T) -> bool
+    {
+        let mut i = 0;
+        while i < self.elements.len() {
+            if f(&self.elements[i]) {
+                i += 1;
+            }
+            else {
+                // Swap removal; the last element moves to `i`, and is checked next.
+                let pos = self.elements[i].id().to_index();
+                self.remove_at(i);
+                self.indices[pos] = INVALID;
+            }
+        }
+    }
+
+    // Set algebra. The `_with` forms work in place; elements of `other` are cloned in.
+    pub fn union_with(&mut self, other: &Self)
+        where T: Clone
+    {
+        for x in other.iter() {
+            if !self.contains(x) {
+                self.insert(x.clone());
+            }
+        }
+    }
+
+    pub fn intersect_with(&mut self, other: &Self)
+    {
+        self.retain(|x| other.contains(x));
+    }
+
+    pub fn difference_with(&mut self, other: &Self)
+    {
+        self.retain(|x| !other.contains(x));
+    }
+
+    pub fn symmetric_difference_with(&mut self, other: &Self)
+        where T: Clone
+    {
+        for x in other.iter() {
+            if self.remove(x).is_none() {
+                self.insert(x.clone());
+            }
+        }
+    }
+
+    pub fn union(&self, other: &Self) -> Self
+        where T: Clone
+    {
+        let mut ret = self.clone();
+        ret.union_with(other);
+        ret
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self
+        where T: Clone
+    {
+        self.iter().filter(|x| other.contains(x)).cloned().collect()
+    }
+
+    pub fn difference(&self, other: &Self) -> Self
+        where T: Clone
+    {
+        self.iter().filter(|x| !other.contains(x)).cloned().collect()
+    }
+
+    pub fn symmetric_difference(&self, other: &Self) -> Self
+        where T: Clone
+    {
+        let mut ret = self.difference(other);
+        ret.extend(other.iter().filter(|x| !self.contains(x)).cloned());
+        ret
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool
+    {
+        self.elements.len() <= other.elements.len() && self.iter().all(|x| other.contains(x))
+    }
+
+    pub fn is_superset(&self, other: &Self) -> bool
+    {
+        other.is_subset(self)
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool
+    {
+        let (small, large) = if self.elements.len() <= other.elements.len() { (self, other) } else { (other, self) };
+        small.iter().all(|x| !large.contains(x))
+    }
 }
 
+macro_rules! set_operators {
+    ($($op:ident::$method:ident => $set_method:ident, $assign_op:ident::$assign_method:ident => $assign_set_method:ident);*) => {
+        $(
+            impl<T> $op<&DenseSet<T>> for &DenseSet<T>
+                where T: Identifier + Clone
+            {
+                type Output = DenseSet<T>;
+
+                fn $method(self, other: &DenseSet<T>) -> DenseSet<T> { self.$set_method(other) }
+            }
+
+            impl<T> $assign_op<&DenseSet<T>> for DenseSet<T>
+                where T: Identifier + Clone
+            {
+                fn $assign_method(&mut self, other: &DenseSet<T>) { self.$assign_set_method(other) }
+            }
+        )*
+    };
+}
+
+set_operators!(
+    BitOr::bitor => union, BitOrAssign::bitor_assign => union_with;
+    BitAnd::bitand => intersection, BitAndAssign::bitand_assign => intersect_with;
+    Sub::sub => difference, SubAssign::sub_assign => difference_with;
+    BitXor::bitxor => symmetric_difference, BitXorAssign::bitxor_assign => symmetric_difference_with
+);
+
 impl<T> FromIterator<T> for DenseSet<T>
     where T: Identifier,
 {
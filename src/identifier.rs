@@ -1,9 +1,95 @@
-pub trait IdLike: Copy + Into<usize> + From<usize> + Eq + Ord
+use std::{
+    convert::TryFrom,
+    num::{NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroUsize}
+};
+
+use crate::nonmax::{NonMaxU8, NonMaxU16, NonMaxU32, NonMaxU64, NonMaxUsize};
+
+// Keys that map to vector indices. The conversion from an index is checked, so that keys may be
+// narrower than `usize`; it never yields null, so that null stays free for sentinels.
+pub trait IdLike: Copy + Eq + Ord
 {
     fn null() -> Self;
     fn is_null(self) -> bool { self == Self::null() }
+
+    fn to_index(self) -> usize;
+    fn try_from_index(index: usize) -> Option<Self>;
+    fn from_index(index: usize) -> Self { Self::try_from_index(index).expect("Index out of range for nicole::IdLike") }
+
+    // Conversions between the null sentinel and `None`.
+    fn into_option(self) -> Option<Self> { if self.is_null() { None } else { Some(self) } }
+    fn from_option(id: Option<Self>) -> Self { id.unwrap_or_else(Self::null) }
+}
+
+macro_rules! impl_id_like {
+    ($($t:ty),*) => {
+        $(
+            impl IdLike for $t
+            {
+                fn null() -> Self { <$t>::MAX }
+                fn to_index(self) -> usize { usize::try_from(self).expect("Id out of range for nicole::IdLike") }
+                fn try_from_index(index: usize) -> Option<Self> { <$t>::try_from(index).ok().filter(|x| *x != <$t>::MAX) }
+            }
+        )*
+    };
+}
+
+impl_id_like!(usize, u64, u32, u16, u8);
+
+// Index `i` is stored as `i + 1`, and null is the maximum value.
+macro_rules! impl_id_like_non_zero {
+    ($($t:ty),*) => {
+        $(
+            impl IdLike for $t
+            {
+                fn null() -> Self { <$t>::MAX }
+                fn to_index(self) -> usize { usize::try_from(self.get()).expect("Id out of range for nicole::IdLike") - 1 }
+
+                fn try_from_index(index: usize) -> Option<Self>
+                {
+                    <Option<$t>>::try_from_index(index).flatten().filter(|x| *x != <$t>::MAX)
+                }
+            }
+        )*
+        impl_id_like_option!($($t => |index: usize| index.checked_add(1).and_then(|x| TryFrom::try_from(x).ok()).and_then(<$t>::new)),*);
+    };
+}
+
+// Niche-packed ids: null is `None`, so an id is as large as the integer, and every value of the integer,
+// including the null of the bare integer, is a key. `None` has no index, and maps to `usize::MAX`, like
+// the null of `usize`.
+macro_rules! impl_id_like_option {
+    ($($t:ty => $from_index:expr),*) => {
+        $(
+            impl IdLike for Option<$t>
+            {
+                fn null() -> Self { None }
+                fn to_index(self) -> usize { self.map_or(usize::MAX, |x| x.to_index()) }
+                fn try_from_index(index: usize) -> Option<Self> { ($from_index)(index).map(Some) }
+            }
+        )*
+    };
 }
 
+impl_id_like_non_zero!(NonZeroUsize, NonZeroU64, NonZeroU32, NonZeroU16, NonZeroU8);
+
+// Index `i` is stored as `i`, and null is the largest value left. Wrap in `Option` for a niche-packed null.
+macro_rules! impl_id_like_non_max {
+    ($($t:ident($int:ty)),*) => {
+        $(
+            impl IdLike for $t
+            {
+                fn null() -> Self { <$t>::new(<$int>::MAX - 1).unwrap() }
+                fn to_index(self) -> usize { usize::try_from(self.get()).expect("Id out of range for nicole::IdLike") }
+                fn try_from_index(index: usize) -> Option<Self> { <Option<$t>>::try_from_index(index).flatten().filter(|x| !x.is_null()) }
+            }
+        )*
+        impl_id_like_option!($($t => |index: usize| <$int>::try_from(index).ok().and_then(<$t>::new)),*);
+    };
+}
+
+impl_id_like_non_max!(NonMaxUsize(usize), NonMaxU64(u64), NonMaxU32(u32), NonMaxU16(u16), NonMaxU8(u8));
+
 pub trait Identifier
 {
     type Id: IdLike;
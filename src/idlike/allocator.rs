@@ -0,0 +1,169 @@
+use std::{cmp::Reverse, collections::BinaryHeap, marker::PhantomData};
+
+use crate::identifier::IdLike;
+
+// Hands out ids, lowest free first. Released ids are kept in a min-heap and reused before fresh ones, so
+// that ids stay dense. With generations, every slot counts its releases, so that a stale (id, generation)
+// pair can be told apart from the current holder of the id.
+#[derive(Debug, Clone)]
+pub struct IdAllocator<K>
+{
+    free: BinaryHeap<Reverse<usize>>,
+    allocated: Vec<bool>,
+    generations: Option<Vec<u32>>,
+    len: usize,
+    phantom: PhantomData<K>
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReleaseError
+{
+    // Never allocated, or already released.
+    NotAllocated,
+    // Allocated, but at another generation; i.e., released and reallocated since.
+    StaleGeneration
+}
+
+impl<K> IdAllocator<K>
+    where K: IdLike
+{
+    pub fn new() -> Self
+    {
+        Self {
+            free: BinaryHeap::new(),
+            allocated: Vec::new(),
+            generations: None,
+            len: 0,
+            phantom: PhantomData
+        }
+    }
+
+    pub fn with_generations() -> Self
+    {
+        Self {
+            generations: Some(Vec::new()),
+            ..Self::new()
+        }
+    }
+
+    // Panics if every id that fits in `K` is allocated. Null is never handed out, since `try_from_index`
+    // never yields it.
+    pub fn allocate(&mut self) -> K
+    {
+        let (pos, id) = match self.free.pop() {
+            Some(Reverse(pos)) => (pos, K::from_index(pos)),
+            None => {
+                let pos = self.allocated.len();
+                // Converted before growing, so that a failed allocation leaves the allocator untouched.
+                let id = K::try_from_index(pos).expect("Out of ids for nicole::IdAllocator");
+                self.allocated.push(false);
+                if let Some(generations) = &mut self.generations {
+                    generations.push(0);
+                }
+                (pos, id)
+            }
+        };
+
+        self.allocated[pos] = true;
+        self.len += 1;
+        id
+    }
+
+    // Like `allocate`, along with the generation of the new id, for `is_current`/`release_generation`.
+    // Without generations, the generation is always 0, which those methods ignore.
+    pub fn allocate_with_generation(&mut self) -> (K, u32)
+    {
+        let id = self.allocate();
+        (id, self.generation(id).unwrap_or(0))
+    }
+
+    pub fn release(&mut self, id: K) -> Result<(), ReleaseError>
+    {
+        let pos = id.to_index();
+
+        if !self.is_allocated(id) {
+            return Err(ReleaseError::NotAllocated);
+        }
+
+        self.allocated[pos] = false;
+        if let Some(generations) = &mut self.generations {
+            generations[pos] = generations[pos].wrapping_add(1);
+        }
+        self.free.push(Reverse(pos));
+        self.len -= 1;
+        Ok(())
+    }
+
+    // Like `release`, but fails if `id` has been reallocated since `generation`. Without generations,
+    // behaves as `release`.
+    pub fn release_generation(&mut self, id: K, generation: u32) -> Result<(), ReleaseError>
+    {
+        if !self.is_allocated(id) {
+            Err(ReleaseError::NotAllocated)
+        }
+        else if self.generation(id).is_some_and(|x| x != generation) {
+            Err(ReleaseError::StaleGeneration)
+        }
+        else {
+            self.release(id)
+        }
+    }
+
+    pub fn is_allocated(&self, id: K) -> bool
+    {
+        let pos = id.to_index();
+        pos < self.allocated.len() && self.allocated[pos]
+    }
+
+    // The current generation of the slot of `id`, if generations are tracked. Starts at 0, and increases on
+    // every release.
+    pub fn generation(&self, id: K) -> Option<u32>
+    {
+        self.generations.as_ref().and_then(|x| x.get(id.to_index()).copied())
+    }
+
+    // Whether `id` is allocated, at `generation`; false for a stale pair, i.e. a use after release.
+    pub fn is_current(&self, id: K, generation: u32) -> bool
+    {
+        self.is_allocated(id) && self.generation(id).is_none_or(|x| x == generation)
+    }
+
+    // One past the largest id ever allocated; dense collections keyed by the ids need this many slots.
+    pub fn high_water_mark(&self) -> usize
+    {
+        self.allocated.len()
+    }
+
+    // The number of ids currently allocated.
+    pub fn len(&self) -> usize
+    {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        self.len == 0
+    }
+
+    // Releases every id. Generations are kept, so that ids issued before remain detectably stale.
+    pub fn clear(&mut self)
+    {
+        for (pos, allocated) in self.allocated.iter_mut().enumerate() {
+            if *allocated {
+                *allocated = false;
+                if let Some(generations) = &mut self.generations {
+                    generations[pos] = generations[pos].wrapping_add(1);
+                }
+            }
+        }
+
+        self.free = (0..self.allocated.len()).map(Reverse).collect();
+        self.len = 0;
+    }
+}
+
+impl<K> Default for IdAllocator<K>
+    where K: IdLike
+{
+    fn default() -> Self { Self::new() }
+}
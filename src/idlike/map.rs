@@ -5,6 +5,7 @@ use crate::identifier::IdLike;
 #[derive(Default, Debug, Clone)]
 pub struct IdMap<K, V> {
     set: Vec<Option<V>>,
+    len: usize,
     phantom: PhantomData<K>
 }
 
@@ -15,37 +16,60 @@ impl<K, V> IdMap<K, V>
     {
         Self {
             set: Vec::new(),
+            len: 0,
             phantom: PhantomData
         }
     }
 
     pub fn contains_key(&self, key: &K) -> bool
     {
-        let pos: usize = (*key).into();
+        let pos = key.to_index();
         pos < self.set.len() && self.set[pos].is_some()
     }
 
     pub fn remove(&mut self, key: &K) -> Option<V>
     {
-        let pos: usize = (*key).into();
+        let pos = key.to_index();
         let mut ret = None;
 
         if pos < self.set.len() {
             std::mem::swap(&mut ret, &mut self.set[pos]);
         }
 
+        if ret.is_some() {
+            self.len -= 1;
+        }
+
         ret
     }
 
-    pub fn insert(&mut self, key: K, value: V)
+    // Returns the previous value of `key`, if any.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V>
     {
-        let pos: usize = key.into();
+        // `from_index` never yields null, so a null element could not be iterated back.
+        assert!(!key.is_null(), "Null key on nicole::IdMap");
+        let pos = key.to_index();
 
         if pos >= self.set.len() {
             self.set.resize_with(pos + 1, || None);
         }
 
-        self.set[pos] = Some(value);
+        let ret = self.set[pos].replace(value);
+        if ret.is_none() {
+            self.len += 1;
+        }
+
+        ret
+    }
+
+    pub fn len(&self) -> usize
+    {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        self.len == 0
     }
 
     pub fn keys<'a>(&'a self) -> impl Iterator<Item = K> + 'a
@@ -53,7 +77,7 @@ impl<K, V> IdMap<K, V>
         self.set
             .iter()
             .enumerate()
-            .filter_map(|(id, elem)| elem.as_ref().map(|_| id.into()))
+            .filter_map(|(id, elem)| elem.as_ref().map(|_| K::from_index(id)))
     }
 
     pub fn iter(&self) -> impl Iterator<Item=(K, &V)>
@@ -62,7 +86,7 @@ impl<K, V> IdMap<K, V>
         self.set
             .iter()
             .enumerate()
-            .filter_map(|(id, elem)| elem.as_ref().map(|x| (id.into(), x)))
+            .filter_map(|(id, elem)| elem.as_ref().map(|x| (K::from_index(id), x)))
     }
 
     pub fn iter_mut(&mut self) -> impl Iterator<Item=(K, &mut V)>
@@ -71,16 +95,17 @@ impl<K, V> IdMap<K, V>
         self.set
             .iter_mut()
             .enumerate()
-            .filter_map(|(id, elem)| elem.as_mut().map(|x| (id.into(), x)))
+            .filter_map(|(id, elem)| elem.as_mut().map(|x| (K::from_index(id), x)))
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn into_iter(self) -> impl Iterator<Item=(K, V)>
         where V: Clone
     {
         self.set
             .into_iter()
             .enumerate()
-            .filter_map(|(id, elem)| elem.map(|x| (id.into(), x)))
+            .filter_map(|(id, elem)| elem.map(|x| (K::from_index(id), x)))
     }
 
     pub fn max_key(&self) -> K
@@ -88,18 +113,18 @@ impl<K, V> IdMap<K, V>
         self.set
             .iter()
             .enumerate()
-            .rfind(|(_, x)| x.is_some()).map(|(i, _)| i.into())
+            .rfind(|(_, x)| x.is_some()).map(|(i, _)| K::from_index(i))
             .unwrap_or_else(|| K::null())
     }
 
     pub fn get(&self, key: &K) -> Option<&V>
     {
-        self.set.get((*key).into()).and_then(|x| x.as_ref())
+        self.set.get(key.to_index()).and_then(|x| x.as_ref())
     }
 
     pub fn get_mut(&mut self, key: &K) -> Option<&mut V>
     {
-        self.set.get_mut((*key).into()).and_then(|x| x.as_mut())
+        self.set.get_mut(key.to_index()).and_then(|x| x.as_mut())
     }
 }
 
@@ -1,2 +1,4 @@
+pub mod allocator;
 pub mod map;
 pub mod set;
+pub mod sparse;
@@ -1,14 +1,20 @@
 use std::{
     iter::FromIterator,
-    fmt::Debug
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Bound, RangeBounds, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Sub, SubAssign}
 };
 
 use crate::identifier::IdLike;
 
+const BITS: usize = u64::BITS as usize;
+
+// A bitset; id `i` is bit `i % 64` of word `i / 64`.
 #[derive(Default, Debug, Clone)]
 pub struct IdSet<T> {
-    set: Vec<bool>,
-    phantom: std::marker::PhantomData<T>
+    words: Vec<u64>,
+    len: usize,
+    phantom: PhantomData<T>
 }
 
 impl<T> IdSet<T>
@@ -17,52 +23,379 @@ impl<T> IdSet<T>
     pub fn new() -> Self
     {
         Self {
-            set: Vec::new(),
-            phantom: Default::default()
+            words: Vec::new(),
+            len: 0,
+            phantom: PhantomData
         }
     }
 
     pub fn contains(&self, value: &T) -> bool
     {
-        let pos: usize = (*value).into();
-        pos < self.set.len() && self.set[pos]
+        let pos = value.to_index();
+        self.words.get(pos / BITS).is_some_and(|word| word & (1 << (pos % BITS)) != 0)
     }
 
-    pub fn remove(&mut self, value: &T)
+    // Returns whether `value` was present.
+    pub fn remove(&mut self, value: &T) -> bool
     {
-        let pos: usize = (*value).into();
+        let pos = value.to_index();
+        let bit = 1 << (pos % BITS);
 
-        if pos < self.set.len() {
-            self.set[pos] = false;
+        match self.words.get_mut(pos / BITS) {
+            Some(word) if *word & bit != 0 => {
+                *word &= !bit;
+                self.len -= 1;
+                true
+            },
+            _ => false
         }
     }
 
-    pub fn insert(&mut self, value: T)
+    // Returns whether `value` was new.
+    pub fn insert(&mut self, value: T) -> bool
     {
-        let pos: usize = value.into();
+        // `from_index` never yields null, so a null element could not be iterated back.
+        assert!(!value.is_null(), "Null id on nicole::IdSet");
+        let pos = value.to_index();
+        let bit = 1 << (pos % BITS);
+
+        if pos / BITS >= self.words.len() {
+            self.words.resize(pos / BITS + 1, 0);
+        }
 
-        if pos >= self.set.len() {
-            self.set.resize(pos + 1, false);
+        let word = &mut self.words[pos / BITS];
+        let ret = *word & bit == 0;
+        if ret {
+            *word |= bit;
+            self.len += 1;
         }
 
-        self.set[pos] = true;
+        ret
+    }
+
+    pub fn len(&self) -> usize
+    {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        self.len == 0
+    }
+
+    // After a whole-set operation, which touches every word anyway.
+    fn recount(&mut self)
+    {
+        self.len = self.words.iter().map(|word| word.count_ones() as usize).sum();
+    }
+
+    pub fn iter(&self) -> Iter<'_, T>
+    {
+        Iter { bits: Bits::new(&self.words), phantom: PhantomData }
     }
 
-    pub fn iter<'a>(&'a self) -> impl Iterator<Item=T> + 'a
+    pub fn max(&self) -> T
     {
-        self.set
+        self.words
+            .iter()
+            .rposition(|&word| word != 0)
+            .map(|i| T::from_index(i * BITS + (BITS - 1 - self.words[i].leading_zeros() as usize)))
+            .unwrap_or_else(|| T::null())
+    }
+
+    // The smallest id in the set, or null if empty.
+    pub fn min(&self) -> T
+    {
+        Bits::new(&self.words[..]).next().map_or_else(T::null, T::from_index)
+    }
+
+    // The smallest id in the set greater than `id`, or null if none.
+    pub fn next_after(&self, id: T) -> T
+    {
+        Bits::starting_at(&self.words[..], id.to_index().saturating_add(1)).next().map_or_else(T::null, T::from_index)
+    }
+
+    // The largest id in the set less than `id`, or null if none.
+    pub fn prev_before(&self, id: T) -> T
+    {
+        let pos = id.to_index().min(self.words.len() * BITS);
+        if pos == 0 {
+            return T::null();
+        }
+
+        let last = pos - 1;
+        let mut index = last / BITS;
+        let mut word = self.words[index] & (!0 >> (BITS - 1 - last % BITS));
+
+        loop {
+            if word != 0 {
+                return T::from_index(index * BITS + (BITS - 1 - word.leading_zeros() as usize));
+            }
+            if index == 0 {
+                return T::null();
+            }
+            index -= 1;
+            word = self.words[index];
+        }
+    }
+
+    // The ids of the set within `range`, in increasing order.
+    pub fn range<R>(&self, range: R) -> impl Iterator<Item=T> + '_
+        where R: RangeBounds<T>
+    {
+        let (start, end) = self.bounds(range);
+        let mut bits = Bits::starting_at(&self.words[..], start);
+
+        std::iter::from_fn(move || bits.next()).take_while(move |&pos| pos < end).map(T::from_index)
+    }
+
+    // The number of ids of the set within `range`.
+    pub fn count_range<R>(&self, range: R) -> usize
+        where R: RangeBounds<T>
+    {
+        let (start, end) = self.bounds(range);
+        if start >= end {
+            return 0;
+        }
+
+        let (first, last) = (start / BITS, (end - 1) / BITS);
+        self.words[first..=last]
             .iter()
             .enumerate()
-            .filter_map(|(id, &exists)| if exists { Some(T::from(id)) } else { None } )
+            .map(|(i, &word)| {
+                let mut word = word;
+                if i == 0 {
+                    word &= !0 << (start % BITS);
+                }
+                if first + i == last {
+                    word &= !0 >> (BITS - 1 - (end - 1) % BITS);
+                }
+                word.count_ones() as usize
+            })
+            .sum()
     }
 
-    pub fn max(&self) -> T
+    // `range` as a half-open range of positions, with the end clamped to the storage.
+    fn bounds<R>(&self, range: R) -> (usize, usize)
+        where R: RangeBounds<T>
+    {
+        let start = match range.start_bound() {
+            Bound::Included(id) => id.to_index(),
+            Bound::Excluded(id) => id.to_index().saturating_add(1),
+            Bound::Unbounded => 0
+        };
+        let end = match range.end_bound() {
+            Bound::Included(id) => id.to_index().saturating_add(1),
+            Bound::Excluded(id) => id.to_index(),
+            Bound::Unbounded => usize::MAX
+        };
+
+        (start, end.min(self.words.len() * BITS))
+    }
+
+    // Set algebra, a word at a time. The `_with` forms work in place.
+    pub fn union_with(&mut self, other: &Self)
+    {
+        if other.words.len() > self.words.len() {
+            self.words.resize(other.words.len(), 0);
+        }
+
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word |= other;
+        }
+        self.recount();
+    }
+
+    pub fn intersect_with(&mut self, other: &Self)
+    {
+        self.words.truncate(other.words.len());
+
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word &= other;
+        }
+        self.recount();
+    }
+
+    pub fn difference_with(&mut self, other: &Self)
+    {
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word &= !other;
+        }
+        self.recount();
+    }
+
+    pub fn symmetric_difference_with(&mut self, other: &Self)
+    {
+        if other.words.len() > self.words.len() {
+            self.words.resize(other.words.len(), 0);
+        }
+
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word ^= other;
+        }
+        self.recount();
+    }
+
+    pub fn union(&self, other: &Self) -> Self
+    {
+        let mut ret = self.clone();
+        ret.union_with(other);
+        ret
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self
+    {
+        let mut ret = self.clone();
+        ret.intersect_with(other);
+        ret
+    }
+
+    pub fn difference(&self, other: &Self) -> Self
+    {
+        let mut ret = self.clone();
+        ret.difference_with(other);
+        ret
+    }
+
+    pub fn symmetric_difference(&self, other: &Self) -> Self
+    {
+        let mut ret = self.clone();
+        ret.symmetric_difference_with(other);
+        ret
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool
     {
-        self.set
+        self.words
             .iter()
             .enumerate()
-            .rfind(|(_, x)| **x).map(|(i, _)| i.into())
-            .unwrap_or_else(|| T::null())
+            .all(|(i, word)| word & !other.words.get(i).copied().unwrap_or(0) == 0)
+    }
+
+    pub fn is_superset(&self, other: &Self) -> bool
+    {
+        other.is_subset(self)
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool
+    {
+        self.words.iter().zip(&other.words).all(|(word, other)| word & other == 0)
+    }
+
+    // The words up to the last nonzero one; removals and intersections leave zero words behind.
+    fn trimmed(&self) -> &[u64]
+    {
+        let len = self.words.iter().rposition(|&word| word != 0).map_or(0, |i| i + 1);
+        &self.words[..len]
+    }
+}
+
+impl<T> PartialEq for IdSet<T>
+    where T: IdLike
+{
+    fn eq(&self, other: &Self) -> bool
+    {
+        self.len == other.len && self.trimmed() == other.trimmed()
+    }
+}
+
+impl<T> Eq for IdSet<T> where T: IdLike {}
+
+macro_rules! set_operators {
+    ($($op:ident::$method:ident => $set_method:ident, $assign_op:ident::$assign_method:ident => $assign_set_method:ident);*) => {
+        $(
+            impl<T> $op<&IdSet<T>> for &IdSet<T>
+                where T: IdLike
+            {
+                type Output = IdSet<T>;
+
+                fn $method(self, other: &IdSet<T>) -> IdSet<T> { self.$set_method(other) }
+            }
+
+            impl<T> $assign_op<&IdSet<T>> for IdSet<T>
+                where T: IdLike
+            {
+                fn $assign_method(&mut self, other: &IdSet<T>) { self.$assign_set_method(other) }
+            }
+        )*
+    };
+}
+
+set_operators!(
+    BitOr::bitor => union, BitOrAssign::bitor_assign => union_with;
+    BitAnd::bitand => intersection, BitAndAssign::bitand_assign => intersect_with;
+    Sub::sub => difference, SubAssign::sub_assign => difference_with;
+    BitXor::bitxor => symmetric_difference, BitXorAssign::bitxor_assign => symmetric_difference_with
+);
+
+// Yields the set bits of a slice of words in increasing order, one trailing zero count per bit.
+struct Bits<W>
+{
+    words: W,
+    index: usize,
+    word: u64
+}
+
+impl<W> Bits<W>
+    where W: AsRef<[u64]>
+{
+    fn new(words: W) -> Self
+    {
+        Self::starting_at(words, 0)
+    }
+
+    // Skips the bits before position `start`.
+    fn starting_at(words: W, start: usize) -> Self
+    {
+        let index = start / BITS;
+        let word = words.as_ref().get(index).map_or(0, |word| word & (!0 << (start % BITS)));
+        Self { words, index, word }
+    }
+
+    fn next(&mut self) -> Option<usize>
+    {
+        while self.word == 0 {
+            self.index += 1;
+            self.word = *self.words.as_ref().get(self.index)?;
+        }
+
+        let bit = self.word.trailing_zeros() as usize;
+        self.word &= self.word - 1;
+        Some(self.index * BITS + bit)
+    }
+}
+
+pub struct Iter<'a, T>
+{
+    bits: Bits<&'a [u64]>,
+    phantom: PhantomData<T>
+}
+
+impl<'a, T> Iterator for Iter<'a, T>
+    where T: IdLike
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        self.bits.next().map(T::from_index)
+    }
+}
+
+pub struct IntoIter<T>
+{
+    bits: Bits<Vec<u64>>,
+    phantom: PhantomData<T>
+}
+
+impl<T> Iterator for IntoIter<T>
+    where T: IdLike
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        self.bits.next().map(T::from_index)
     }
 }
 
@@ -90,7 +423,7 @@ impl<T> Extend<T> for IdSet<T>
         IT: IntoIterator<Item = T>,
     {
         for x in iter {
-            self.insert(x)
+            self.insert(x);
         }
     }
 }
@@ -99,17 +432,22 @@ impl<T> IntoIterator for IdSet<T>
     where T: IdLike
 {
     type Item = T;
-    // TODO check if impl Iterator<Item=T> applies after https://github.com/rust-lang/rust/issues/63063
-    // has been merged
-    type IntoIter = std::vec::IntoIter<T>;
+    type IntoIter = IntoIter<T>;
 
     fn into_iter(self) -> Self::IntoIter
-    {   
-        self.set
-            .into_iter()
-            .enumerate()
-            .filter_map(|(id, exists)| if exists { Some(T::from(id)) } else { None } )
-            .collect::<Vec<_>>()
-            .into_iter()
+    {
+        IntoIter { bits: Bits::new(self.words), phantom: PhantomData }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a IdSet<T>
+    where T: IdLike
+{
+    type Item = T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        self.iter()
     }
 }
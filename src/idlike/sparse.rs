@@ -0,0 +1,622 @@
+use std::{
+    iter::FromIterator,
+    fmt::{self, Debug},
+    marker::PhantomData,
+    ops::{Bound, RangeBounds, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Sub, SubAssign}
+};
+
+use crate::identifier::IdLike;
+
+const BITS: usize = u64::BITS as usize;
+const LEVEL_BITS: u32 = BITS.trailing_zeros();
+const MAX_HEIGHT: usize = usize::BITS.div_ceil(LEVEL_BITS) as usize;
+
+// An `IdSet` for huge, sparse ids: a 64-ary trie of bit words, where empty subtrees are not stored.
+// Leaves are the words of a plain `IdSet`; every inner node has a bit per child, set when the child is
+// nonempty, and stores only those children, in bit order. The trie grows in height as larger ids are
+// inserted, so small ids stay cheap. Nodes live in a vector, and freed ones are reused.
+#[derive(Clone)]
+pub struct SparseIdSet<T>
+{
+    nodes: Vec<Node>,
+    free: Vec<usize>,
+    root: usize,
+    // The root covers ids below 64^height.
+    height: u32,
+    len: usize,
+    phantom: PhantomData<T>
+}
+
+#[derive(Clone, Default)]
+struct Node
+{
+    mask: u64,
+    children: Vec<usize>
+}
+
+impl<T> Default for SparseIdSet<T>
+{
+    fn default() -> Self
+    {
+        Self {
+            nodes: vec![Node::default()],
+            free: Vec::new(),
+            root: 0,
+            height: 1,
+            len: 0,
+            phantom: PhantomData
+        }
+    }
+}
+
+impl<T> Debug for SparseIdSet<T>
+    where T: IdLike + Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T> SparseIdSet<T>
+{
+    fn covers(&self, pos: usize) -> bool
+    {
+        self.height * LEVEL_BITS >= usize::BITS || pos >> (self.height * LEVEL_BITS) == 0
+    }
+
+    fn digit(pos: usize, level: u32) -> usize
+    {
+        (pos >> (level * LEVEL_BITS)) & (BITS - 1)
+    }
+
+    fn child(&self, node: usize, digit: usize) -> Option<usize>
+    {
+        let node = &self.nodes[node];
+        if node.mask & (1 << digit) == 0 {
+            None
+        }
+        else {
+            Some(node.children[(node.mask & ((1 << digit) - 1)).count_ones() as usize])
+        }
+    }
+
+    fn child_or_insert(&mut self, node: usize, digit: usize) -> usize
+    {
+        if let Some(child) = self.child(node, digit) {
+            return child;
+        }
+
+        let child = self.alloc(Node::default());
+        let node = &mut self.nodes[node];
+        let rank = (node.mask & ((1 << digit) - 1)).count_ones() as usize;
+        node.children.insert(rank, child);
+        node.mask |= 1 << digit;
+        child
+    }
+
+    fn alloc(&mut self, node: Node) -> usize
+    {
+        match self.free.pop() {
+            Some(index) => {
+                self.nodes[index] = node;
+                index
+            },
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    fn remove_child(&mut self, node: usize, digit: usize)
+    {
+        let node = &mut self.nodes[node];
+        let rank = (node.mask & ((1 << digit) - 1)).count_ones() as usize;
+        let child = node.children.remove(rank);
+        node.mask &= !(1 << digit);
+
+        self.nodes[child] = Node::default();
+        self.free.push(child);
+    }
+
+    fn grow(&mut self, pos: usize)
+    {
+        while !self.covers(pos) {
+            // An empty root covers any range, so only a nonempty one needs a parent.
+            if self.nodes[self.root].mask != 0 {
+                self.root = self.alloc(Node { mask: 1, children: vec![self.root] });
+            }
+            self.height += 1;
+        }
+    }
+
+    // The leaf word holding the bits of ids `base..base + 64`.
+    fn word(&self, base: usize) -> u64
+    {
+        if !self.covers(base) {
+            return 0;
+        }
+
+        let mut node = self.root;
+        for level in (1..self.height).rev() {
+            match self.child(node, Self::digit(base, level)) {
+                Some(child) => node = child,
+                None => return 0
+            }
+        }
+        self.nodes[node].mask
+    }
+
+    // Creates the path to the leaf of `base` as needed, and prunes it when `word` is empty.
+    fn set_word(&mut self, base: usize, word: u64)
+    {
+        if word != 0 {
+            self.grow(base);
+
+            let mut node = self.root;
+            for level in (1..self.height).rev() {
+                node = self.child_or_insert(node, Self::digit(base, level));
+            }
+
+            let leaf = &mut self.nodes[node].mask;
+            self.len = self.len - leaf.count_ones() as usize + word.count_ones() as usize;
+            *leaf = word;
+        }
+        else if self.covers(base) {
+            let mut path = [(0, 0); MAX_HEIGHT];
+            let mut node = self.root;
+            for level in (1..self.height).rev() {
+                let digit = Self::digit(base, level);
+                match self.child(node, digit) {
+                    Some(child) => {
+                        path[level as usize] = (node, digit);
+                        node = child;
+                    },
+                    None => return
+                }
+            }
+
+            self.len -= self.nodes[node].mask.count_ones() as usize;
+            self.nodes[node].mask = 0;
+
+            // The root is kept, even when empty.
+            for &(parent, digit) in &path[1..self.height as usize] {
+                if self.nodes[node].mask != 0 {
+                    break;
+                }
+                self.remove_child(parent, digit);
+                node = parent;
+            }
+        }
+    }
+
+    // The first nonempty leaf word holding an id not less than `start`, with the bits below `start`
+    // cleared; empty subtrees are skipped by their parent masks.
+    fn next_word(&self, start: usize) -> Option<(usize, u64)>
+    {
+        if !self.covers(start) {
+            return None;
+        }
+        self.next_word_in(self.root, self.height - 1, 0, start)
+    }
+
+    fn next_word_in(&self, node: usize, level: u32, base: usize, start: usize) -> Option<(usize, u64)>
+    {
+        let mask = self.nodes[node].mask;
+        if level == 0 {
+            let word = mask & (!0 << (start - base));
+            return if word != 0 { Some((base, word)) } else { None };
+        }
+
+        let shift = level * LEVEL_BITS;
+        let mut candidates = mask & (!0 << ((start - base) >> shift));
+        while candidates != 0 {
+            let digit = candidates.trailing_zeros() as usize;
+            let child_base = base + (digit << shift);
+            let child = self.child(node, digit).unwrap();
+
+            // Only the child holding `start` may have nothing after it.
+            if let Some(ret) = self.next_word_in(child, level - 1, child_base, start.max(child_base)) {
+                return Some(ret);
+            }
+            candidates &= candidates - 1;
+        }
+        None
+    }
+
+    // The last nonempty leaf word holding an id less than `end`, with the bits from `end` on cleared.
+    fn prev_word(&self, end: usize) -> Option<(usize, u64)>
+    {
+        if end == 0 {
+            return None;
+        }
+        self.prev_word_in(self.root, self.height - 1, 0, end)
+    }
+
+    fn prev_word_in(&self, node: usize, level: u32, base: usize, end: usize) -> Option<(usize, u64)>
+    {
+        let mask = self.nodes[node].mask;
+        let last = end - 1 - base;
+        if level == 0 {
+            let word = if last >= BITS - 1 { mask } else { mask & (!0 >> (BITS - 1 - last)) };
+            return if word != 0 { Some((base, word)) } else { None };
+        }
+
+        let shift = level * LEVEL_BITS;
+        let mut candidates = mask & (!0 >> (BITS - 1 - (last >> shift).min(BITS - 1)));
+        while candidates != 0 {
+            let digit = BITS - 1 - candidates.leading_zeros() as usize;
+            let child_base = base + (digit << shift);
+            let child = self.child(node, digit).unwrap();
+
+            if let Some(ret) = self.prev_word_in(child, level - 1, child_base, end) {
+                return Some(ret);
+            }
+            candidates &= !(1 << digit);
+        }
+        None
+    }
+
+    fn words(&self) -> impl Iterator<Item=(usize, u64)> + '_
+    {
+        let mut current = self.next_word(0);
+        std::iter::from_fn(move || {
+            let ret = current?;
+            current = ret.0.checked_add(BITS).and_then(|next| self.next_word(next));
+            Some(ret)
+        })
+    }
+}
+
+// Yields the set bits of the leaf words of `set`, starting from `current`.
+fn advance<T>(set: &SparseIdSet<T>, current: &mut Option<(usize, u64)>) -> Option<usize>
+{
+    loop {
+        let (base, word) = (*current)?;
+        if word != 0 {
+            *current = Some((base, word & (word - 1)));
+            return Some(base + word.trailing_zeros() as usize);
+        }
+        *current = base.checked_add(BITS).and_then(|next| set.next_word(next));
+    }
+}
+
+impl<T> SparseIdSet<T>
+    where T: IdLike
+{
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    pub fn contains(&self, value: &T) -> bool
+    {
+        let pos = value.to_index();
+        self.word(pos & !(BITS - 1)) & (1 << (pos % BITS)) != 0
+    }
+
+    // Returns whether `value` was present.
+    pub fn remove(&mut self, value: &T) -> bool
+    {
+        let pos = value.to_index();
+        let (base, bit) = (pos & !(BITS - 1), 1 << (pos % BITS));
+
+        let word = self.word(base);
+        if word & bit == 0 {
+            return false;
+        }
+
+        self.set_word(base, word & !bit);
+        true
+    }
+
+    // Returns whether `value` was new.
+    pub fn insert(&mut self, value: T) -> bool
+    {
+        // `from_index` never yields null, so a null element could not be iterated back.
+        assert!(!value.is_null(), "Null id on nicole::SparseIdSet");
+        let pos = value.to_index();
+        let (base, bit) = (pos & !(BITS - 1), 1 << (pos % BITS));
+
+        let word = self.word(base);
+        if word & bit != 0 {
+            return false;
+        }
+
+        self.set_word(base, word | bit);
+        true
+    }
+
+    pub fn len(&self) -> usize
+    {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        self.len == 0
+    }
+
+    pub fn iter(&self) -> Iter<'_, T>
+    {
+        Iter { set: self, current: self.next_word(0) }
+    }
+
+    // The largest id in the set, or null if empty.
+    pub fn max(&self) -> T
+    {
+        self.prev_word(usize::MAX).map_or_else(T::null, |(base, word)| T::from_index(base + (BITS - 1 - word.leading_zeros() as usize)))
+    }
+
+    // The smallest id in the set, or null if empty.
+    pub fn min(&self) -> T
+    {
+        self.next_word(0).map_or_else(T::null, |(base, word)| T::from_index(base + word.trailing_zeros() as usize))
+    }
+
+    // The smallest id in the set greater than `id`, or null if none.
+    pub fn next_after(&self, id: T) -> T
+    {
+        self.next_word(id.to_index().saturating_add(1))
+            .map_or_else(T::null, |(base, word)| T::from_index(base + word.trailing_zeros() as usize))
+    }
+
+    // The largest id in the set less than `id`, or null if none.
+    pub fn prev_before(&self, id: T) -> T
+    {
+        self.prev_word(id.to_index())
+            .map_or_else(T::null, |(base, word)| T::from_index(base + (BITS - 1 - word.leading_zeros() as usize)))
+    }
+
+    // The ids of the set within `range`, in increasing order.
+    pub fn range<R>(&self, range: R) -> impl Iterator<Item=T> + '_
+        where R: RangeBounds<T>
+    {
+        let (start, end) = bounds(range);
+        let mut current = self.next_word(start);
+
+        std::iter::from_fn(move || advance(self, &mut current)).take_while(move |&pos| pos < end).map(T::from_index)
+    }
+
+    // The number of ids of the set within `range`.
+    pub fn count_range<R>(&self, range: R) -> usize
+        where R: RangeBounds<T>
+    {
+        let (start, end) = bounds(range);
+        let mut ret = 0;
+
+        let mut current = if start < end { self.next_word(start) } else { None };
+        while let Some((base, word)) = current {
+            if base >= end {
+                break;
+            }
+
+            let word = if end - base >= BITS { word } else { word & ((1 << (end - base)) - 1) };
+            ret += word.count_ones() as usize;
+            current = base.checked_add(BITS).and_then(|next| self.next_word(next));
+        }
+        ret
+    }
+
+    // Set algebra, a leaf word at a time. The `_with` forms work in place.
+    pub fn union_with(&mut self, other: &Self)
+    {
+        for (base, word) in other.words() {
+            self.set_word(base, self.word(base) | word);
+        }
+    }
+
+    pub fn intersect_with(&mut self, other: &Self)
+    {
+        let words: Vec<_> = self.words().collect();
+        for (base, word) in words {
+            self.set_word(base, word & other.word(base));
+        }
+    }
+
+    pub fn difference_with(&mut self, other: &Self)
+    {
+        for (base, word) in other.words() {
+            self.set_word(base, self.word(base) & !word);
+        }
+    }
+
+    pub fn symmetric_difference_with(&mut self, other: &Self)
+    {
+        for (base, word) in other.words() {
+            self.set_word(base, self.word(base) ^ word);
+        }
+    }
+
+    pub fn union(&self, other: &Self) -> Self
+    {
+        let mut ret = self.clone();
+        ret.union_with(other);
+        ret
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self
+    {
+        let mut ret = Self::new();
+        for (base, word) in self.words() {
+            ret.set_word(base, word & other.word(base));
+        }
+        ret
+    }
+
+    pub fn difference(&self, other: &Self) -> Self
+    {
+        let mut ret = self.clone();
+        ret.difference_with(other);
+        ret
+    }
+
+    pub fn symmetric_difference(&self, other: &Self) -> Self
+    {
+        let mut ret = self.clone();
+        ret.symmetric_difference_with(other);
+        ret
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool
+    {
+        self.len <= other.len && self.words().all(|(base, word)| word & !other.word(base) == 0)
+    }
+
+    pub fn is_superset(&self, other: &Self) -> bool
+    {
+        other.is_subset(self)
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool
+    {
+        self.words().all(|(base, word)| word & other.word(base) == 0)
+    }
+}
+
+// `range` as a half-open range of positions.
+fn bounds<T, R>(range: R) -> (usize, usize)
+    where
+        T: IdLike,
+        R: RangeBounds<T>
+{
+    let start = match range.start_bound() {
+        Bound::Included(id) => id.to_index(),
+        Bound::Excluded(id) => id.to_index().saturating_add(1),
+        Bound::Unbounded => 0
+    };
+    let end = match range.end_bound() {
+        Bound::Included(id) => id.to_index().saturating_add(1),
+        Bound::Excluded(id) => id.to_index(),
+        Bound::Unbounded => usize::MAX
+    };
+
+    (start, end)
+}
+
+impl<T> PartialEq for SparseIdSet<T>
+    where T: IdLike
+{
+    fn eq(&self, other: &Self) -> bool
+    {
+        self.len == other.len && self.words().all(|(base, word)| word == other.word(base))
+    }
+}
+
+impl<T> Eq for SparseIdSet<T> where T: IdLike {}
+
+macro_rules! set_operators {
+    ($($op:ident::$method:ident => $set_method:ident, $assign_op:ident::$assign_method:ident => $assign_set_method:ident);*) => {
+        $(
+            impl<T> $op<&SparseIdSet<T>> for &SparseIdSet<T>
+                where T: IdLike
+            {
+                type Output = SparseIdSet<T>;
+
+                fn $method(self, other: &SparseIdSet<T>) -> SparseIdSet<T> { self.$set_method(other) }
+            }
+
+            impl<T> $assign_op<&SparseIdSet<T>> for SparseIdSet<T>
+                where T: IdLike
+            {
+                fn $assign_method(&mut self, other: &SparseIdSet<T>) { self.$assign_set_method(other) }
+            }
+        )*
+    };
+}
+
+set_operators!(
+    BitOr::bitor => union, BitOrAssign::bitor_assign => union_with;
+    BitAnd::bitand => intersection, BitAndAssign::bitand_assign => intersect_with;
+    Sub::sub => difference, SubAssign::sub_assign => difference_with;
+    BitXor::bitxor => symmetric_difference, BitXorAssign::bitxor_assign => symmetric_difference_with
+);
+
+pub struct Iter<'a, T>
+{
+    set: &'a SparseIdSet<T>,
+    current: Option<(usize, u64)>
+}
+
+impl<'a, T> Iterator for Iter<'a, T>
+    where T: IdLike
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        advance(self.set, &mut self.current).map(T::from_index)
+    }
+}
+
+pub struct IntoIter<T>
+{
+    set: SparseIdSet<T>,
+    current: Option<(usize, u64)>
+}
+
+impl<T> Iterator for IntoIter<T>
+    where T: IdLike
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        advance(&self.set, &mut self.current).map(T::from_index)
+    }
+}
+
+impl<T> FromIterator<T> for SparseIdSet<T>
+    where T: IdLike,
+{
+    fn from_iter<IT>(iter: IT) -> Self
+    where
+        IT: IntoIterator<Item = T>,
+    {
+        let mut ret = SparseIdSet::new();
+        for item in iter.into_iter() {
+            ret.insert(item);
+        }
+
+        ret
+    }
+}
+
+impl<T> Extend<T> for SparseIdSet<T>
+    where T: IdLike
+{
+    fn extend<IT>(&mut self, iter: IT)
+    where
+        IT: IntoIterator<Item = T>,
+    {
+        for x in iter {
+            self.insert(x);
+        }
+    }
+}
+
+impl<T> IntoIterator for SparseIdSet<T>
+    where T: IdLike
+{
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        let current = self.next_word(0);
+        IntoIter { set: self, current }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SparseIdSet<T>
+    where T: IdLike
+{
+    type Item = T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        self.iter()
+    }
+}
@@ -1,13 +1,27 @@
-use std::ops::Index;
+use std::{cmp::Ordering, ops::Index};
 
 pub trait IndexExt<IndexType>: Index<IndexType>
 {
     fn valid(&self, index: &IndexType) -> bool;
 }
 
+// Besides the indices of its elements, every collection has two sentinel indices, which are never
+// `valid`: `past_end()` is past the last element, and `rend()` is before the first one. Thus,
+// `[begin(), past_end())` covers every element, and is empty exactly when `begin() == past_end()`.
+//
+// Moving never goes beyond the sentinels: incrementing the last element (`rbegin()`) yields
+// `past_end()`, decrementing the first element yields `rend()`, and both sentinels saturate
+// (incrementing `past_end()` or decrementing `rend()` is a no-op). Moving back from a sentinel enters
+// the collection again: incrementing `rend()` yields `begin()`, and decrementing `past_end()` yields
+// `rbegin()`. `move_forward` and `move_backward` saturate the same way.
+//
+// Moving an index that does not belong to the collection (e.g. one that has been removed) is
+// unspecified, but never unsafe.
+
 pub trait ForwardIndex<IndexType>: IndexExt<IndexType>
 {
     fn begin(&self) -> IndexType;
+    fn past_end(&self) -> IndexType;
     fn increment(&self, idx: &mut IndexType);
     fn next(&self, mut idx: IndexType) -> IndexType { self.increment(&mut idx); idx }
     fn move_forward(&self, idx: &mut IndexType, distance: usize) { for _ in 0..distance { self.increment(idx) } }
@@ -15,10 +29,16 @@ pub trait ForwardIndex<IndexType>: IndexExt<IndexType>
 
 pub trait BackwardIndex<IndexType>: IndexExt<IndexType>
 {
-    fn end(&self) -> IndexType;
+    fn rbegin(&self) -> IndexType;
+    fn rend(&self) -> IndexType;
     fn decrement(&self, idx: &mut IndexType);
     fn prev(&self, mut idx: IndexType) -> IndexType { self.decrement(&mut idx); idx }
     fn move_backward(&self, idx: &mut IndexType, distance: usize) { for _ in 0..distance { self.decrement(idx) } }
+
+    // The name of `rbegin` before 0.5. The past-the-end index is `ForwardIndex::past_end`, under a new
+    // name, so that no old caller silently gets it instead of the last element.
+    #[deprecated(since = "0.5.0", note = "renamed to `rbegin`; the past-the-end index is `ForwardIndex::past_end`")]
+    fn end(&self) -> IndexType { self.rbegin() }
 }
 
 pub trait BidirectionalIndex<IndexType>: ForwardIndex<IndexType> + BackwardIndex<IndexType>
@@ -33,4 +53,20 @@ pub trait BidirectionalIndex<IndexType>: ForwardIndex<IndexType> + BackwardIndex
     }
 }
 
-impl<IndexType, T> BidirectionalIndex<IndexType> for T where T: ForwardIndex<IndexType> + BackwardIndex<IndexType> {}
+// Collections whose indices move in O(1). Positions are ordered as `rend() < begin() < ... < rbegin() <
+// past_end()`, and `jump` saturates at the sentinels like the other moves.
+pub trait RandomAccessIndex<IndexType>: BidirectionalIndex<IndexType>
+{
+    // The number of increments from `a` to `b` (negative if `b` comes before `a`).
+    fn distance(&self, a: &IndexType, b: &IndexType) -> isize;
+    fn jump(&self, idx: &mut IndexType, distance: isize);
+    fn cmp_indices(&self, a: &IndexType, b: &IndexType) -> Ordering { self.distance(b, a).cmp(&0) }
+}
+
+// Swaps the elements at two indices, leaving the indices themselves in place.
+pub trait SwapIndex<IndexType>: IndexExt<IndexType>
+{
+    fn swap_indices(&mut self, a: IndexType, b: IndexType);
+}
+
+impl<IndexType, T> BidirectionalIndex<IndexType> for T where T: ForwardIndex<IndexType> + BackwardIndex<IndexType> + ?Sized {}
@@ -0,0 +1,140 @@
+use std::{
+    borrow::Borrow,
+    collections::HashMap,
+    hash::Hash,
+    ops::{Deref, Index}
+};
+
+use crate::{IdLike, typedvec::TypedVec};
+
+// Maps values to dense ids, in order of first interning, so that they can key `TypedVec`, `IdMap` and
+// the like. Every value is stored twice, once per direction.
+#[derive(Clone, Debug)]
+pub struct Interner<T, K>
+{
+    inner: FrozenInterner<T, K>
+}
+
+// A read-only `Interner`, as returned by `freeze`.
+#[derive(Clone, Debug)]
+pub struct FrozenInterner<T, K>
+{
+    values: TypedVec<K, T>,
+    ids: HashMap<T, K>
+}
+
+impl<T, K> Interner<T, K>
+    where
+        T: Eq + Hash,
+        K: IdLike
+{
+    pub fn new() -> Self
+    {
+        Self { inner: FrozenInterner::default() }
+    }
+
+    // Returns the id of `value`, interning a copy of it first if needed. Accepts borrowed forms, e.g. a
+    // `&str` for an `Interner<String, _>`. Panics if the ids that fit in `K` run out, keeping one spare for
+    // `TypedVec::end`.
+    pub fn intern<Q>(&mut self, value: &Q) -> K
+        where
+            T: Borrow<Q>,
+            Q: Eq + Hash + ToOwned<Owned = T> + ?Sized
+    {
+        if let Some(id) = self.inner.get(value) {
+            return id;
+        }
+
+        // The id of the new slot; never null, wherever null lies among the indices of `K`.
+        let id = self.inner.values.push(value.to_owned());
+        self.inner.ids.insert(value.to_owned(), id);
+        id
+    }
+
+    pub fn freeze(self) -> FrozenInterner<T, K>
+    {
+        self.inner
+    }
+}
+
+// Not derived, since that would need `K: Default`, which ids such as `NonZeroU32` are not.
+impl<T, K> Default for Interner<T, K>
+    where
+        T: Eq + Hash,
+        K: IdLike
+{
+    fn default() -> Self { Self::new() }
+}
+
+impl<T, K> Default for FrozenInterner<T, K>
+    where
+        T: Eq + Hash,
+        K: IdLike
+{
+    fn default() -> Self
+    {
+        Self { values: TypedVec::new(), ids: HashMap::new() }
+    }
+}
+
+impl<T, K> Deref for Interner<T, K>
+{
+    type Target = FrozenInterner<T, K>;
+
+    fn deref(&self) -> &Self::Target { &self.inner }
+}
+
+impl<T, K> FrozenInterner<T, K>
+    where
+        T: Eq + Hash,
+        K: IdLike
+{
+    pub fn get<Q>(&self, value: &Q) -> Option<K>
+        where
+            T: Borrow<Q>,
+            Q: Eq + Hash + ?Sized
+    {
+        self.ids.get(value).copied()
+    }
+
+    pub fn resolve(&self, id: K) -> Option<&T>
+    {
+        self.values.get(id)
+    }
+
+    pub fn len(&self) -> usize
+    {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        self.values.is_empty()
+    }
+
+    // In id order, i.e. in order of interning.
+    pub fn iter(&self) -> impl Iterator<Item=(K, &T)>
+    {
+        self.values.iter()
+    }
+}
+
+impl<T, K> Index<K> for FrozenInterner<T, K>
+    where
+        T: Eq + Hash,
+        K: IdLike
+{
+    type Output = T;
+
+    fn index(&self, id: K) -> &Self::Output { self.resolve(id).expect("Trying to resolve nonexistent id!") }
+}
+
+impl<T, K> Index<K> for Interner<T, K>
+    where
+        T: Eq + Hash,
+        K: IdLike
+{
+    type Output = T;
+
+    fn index(&self, id: K) -> &Self::Output { &self.inner[id] }
+}
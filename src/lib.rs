@@ -1,18 +1,34 @@
+pub mod algorithm;
+pub mod brand;
 pub mod dense;
 pub mod identifier;
 pub mod idlike;
 pub mod index;
+pub mod interner;
+pub mod nonmax;
+pub mod range;
+pub mod safevec;
 pub mod typedvec;
+pub mod unrolled;
+pub mod vecforwardlist;
 pub mod veclist;
 
 #[cfg(feature = "unsafe")] pub mod linkedlist;
 
 pub use identifier::{IdLike, Identifier};
-pub use index::{IndexExt, ForwardIndex, BackwardIndex, BidirectionalIndex};
+pub use nonmax::{NonMaxUsize, NonMaxU64, NonMaxU32, NonMaxU16, NonMaxU8};
+#[cfg(feature = "derive")] pub use nicole_derive::IdLike;
+pub use index::{IndexExt, ForwardIndex, BackwardIndex, BidirectionalIndex, RandomAccessIndex, SwapIndex};
+pub use range::{IndexRange, IndexIterExt};
+pub use brand::{branded, Branded, BrandedVec, Tagged};
+pub use safevec::SafeVec;
+pub use interner::{Interner, FrozenInterner};
 
-pub use veclist::VecList;
+pub use veclist::{VecList, VecListPool, CircularVecList};
+pub use vecforwardlist::VecForwardList;
+pub use unrolled::UnrolledVecList;
 pub use dense::{map::DenseMap, set::DenseSet};
-pub use idlike::{map::IdMap, set::IdSet};
+pub use idlike::{allocator::IdAllocator, map::IdMap, set::IdSet, sparse::SparseIdSet};
 
 #[cfg(feature = "unsafe")] pub use linkedlist::LinkedList;
 
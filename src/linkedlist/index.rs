@@ -24,11 +24,7 @@ impl<T> Clone for LinkedListIndex<T>
 {
     fn clone(&self) -> Self
     {
-        Self {
-            list_tag: self.list_tag.clone(),
-            node_tag: self.node_tag.clone(),
-            list_ptr: self.list_ptr
-        }
+        *self
     }
 }
 
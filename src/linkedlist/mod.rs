@@ -1,4 +1,5 @@
 use std::{
+    cmp::Ordering,
     mem::MaybeUninit,
     ops::{Index, IndexMut},
     fmt::{self, Debug, Formatter},
@@ -7,7 +8,7 @@ use std::{
 
 use uuid::Uuid;
 
-use crate::index::{IndexExt, BackwardIndex, ForwardIndex};
+use crate::index::{IndexExt, BackwardIndex, ForwardIndex, SwapIndex};
 
 pub mod index;
 pub mod iter;
@@ -105,7 +106,7 @@ impl<T> LinkedList<T>
 
     unsafe fn new_node(&mut self, element: T, prev: *mut ListNode<T>, next: *mut ListNode<T>) -> *mut ListNode<T>
     {
-        let list_node = self.free.pop().unwrap_or_else(|| Box::into_raw(Box::new(Default::default())));
+        let list_node = self.free.pop().unwrap_or_else(|| Box::into_raw(Box::default()));
 
         *list_node = ListNode {
             element: MaybeUninit::new(element),
@@ -114,7 +115,7 @@ impl<T> LinkedList<T>
             prev
         };
 
-        return list_node;
+        list_node
     }
 
     // Insert does not invalidate any indices
@@ -159,6 +160,11 @@ impl<T> LinkedList<T>
         self.iter().count()
     }
 
+    pub fn is_empty(&self) -> bool
+    {
+        unsafe { (*self.first).next == self.last }
+    }
+
     pub fn iter(&self) -> impl Iterator<Item=&T>
     {
         unsafe {
@@ -181,6 +187,66 @@ impl<T> LinkedList<T>
         }
     }
 
+    fn node_ptrs(&self) -> Vec<*mut ListNode<T>>
+    {
+        let mut ret = Vec::new();
+
+        unsafe {
+            let mut curr = (*self.first).next;
+            while curr != self.last {
+                ret.push(curr);
+                curr = (*curr).next;
+            }
+        }
+
+        ret
+    }
+
+    // Nodes are only relinked, never moved or retagged, so all indices stay valid.
+    fn relink(&mut self, order: &[*mut ListNode<T>])
+    {
+        unsafe {
+            let mut prev = self.first;
+            for &curr in order {
+                (*prev).next = curr;
+                (*curr).prev = prev;
+                prev = curr;
+            }
+            (*prev).next = self.last;
+            (*self.last).prev = prev;
+        }
+    }
+
+    pub fn sort(&mut self)
+        where T: Ord
+    {
+        self.sort_by(|a, b| a.cmp(b));
+    }
+
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+        where
+            K: Ord,
+            F: FnMut(&T) -> K
+    {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    // Stable merge sort over the node pointers, followed by a relink.
+    pub fn sort_by<F>(&mut self, mut compare: F)
+        where F: FnMut(&T, &T) -> Ordering
+    {
+        let mut order = self.node_ptrs();
+        order.sort_by(|&a, &b| unsafe { compare((*a).element.assume_init_ref(), (*b).element.assume_init_ref()) });
+        self.relink(&order);
+    }
+
+    pub fn reverse(&mut self)
+    {
+        let mut order = self.node_ptrs();
+        order.reverse();
+        self.relink(&order);
+    }
+
     // Merge does not invalidate any indices (makes self.tag a set)
     pub fn merge(&mut self, mut rhs: LinkedList<T>, index: LinkedListIndex<T>)
     {
@@ -275,30 +341,52 @@ impl<T> IndexExt<LinkedListIndex<T>> for LinkedList<T>
     }
 }
 
-impl<T> ForwardIndex<LinkedListIndex<T>> for LinkedList<T>
+impl<T> SwapIndex<LinkedListIndex<T>> for LinkedList<T>
 {
-    fn begin(&self) -> LinkedListIndex<T>
+    fn swap_indices(&mut self, a: LinkedListIndex<T>, b: LinkedListIndex<T>)
     {
+        self.validate_index(&a);
+        self.validate_index(&b);
+
         unsafe {
-            let list_ptr = (*self.first).next;
+            std::ptr::swap(&mut (*a.list_ptr).element, &mut (*b.list_ptr).element);
+        }
+    }
+}
 
-            LinkedListIndex {
-                list_tag: self.tag.first(),
-                node_tag: (*list_ptr).tag,
-                list_ptr
-            }
+impl<T> LinkedList<T>
+{
+    // `list_ptr` must be a node of this list, or one of its sentinels.
+    unsafe fn index_of(&self, list_ptr: *mut ListNode<T>) -> LinkedListIndex<T>
+    {
+        LinkedListIndex {
+            list_tag: self.tag.first(),
+            node_tag: (*list_ptr).tag,
+            list_ptr
         }
     }
+}
+
+// The sentinel nodes double as the `rend` and `end` indices. They are reported as OutOfBounds by
+// `try_validate_index`, and moving away from them is handled separately.
+impl<T> ForwardIndex<LinkedListIndex<T>> for LinkedList<T>
+{
+    fn begin(&self) -> LinkedListIndex<T>
+    {
+        unsafe { self.index_of((*self.first).next) }
+    }
+
+    fn past_end(&self) -> LinkedListIndex<T>
+    {
+        unsafe { self.index_of(self.last) }
+    }
 
     fn increment(&self, index: &mut LinkedListIndex<T>)
     {
         unsafe {
             match self.try_validate_index(index) {
-                Ok(()) => {
-                    let next = (*index.list_ptr).next;
-                    index.list_ptr = next;
-                    index.node_tag = (*next).tag;
-                },
+                Ok(()) => *index = self.index_of((*index.list_ptr).next),
+                Err(IndexError::OutOfBounds) if index.list_ptr == self.first => *index = self.begin(),
                 Err(IndexError::OutOfBounds) => (),
                 other_err => other_err.unwrap()
             }
@@ -308,29 +396,23 @@ impl<T> ForwardIndex<LinkedListIndex<T>> for LinkedList<T>
 
 impl<T> BackwardIndex<LinkedListIndex<T>> for LinkedList<T>
 {
-    fn end(&self) -> LinkedListIndex<T>
+    fn rbegin(&self) -> LinkedListIndex<T>
     {
-        unsafe {
-            let list_ptr = (*self.last).prev;
+        unsafe { self.index_of((*self.last).prev) }
+    }
 
-            LinkedListIndex {
-                list_tag: self.tag.first(),
-                node_tag: (*list_ptr).tag,
-                list_ptr
-            }
-        }
+    fn rend(&self) -> LinkedListIndex<T>
+    {
+        unsafe { self.index_of(self.first) }
     }
 
     fn decrement(&self, index: &mut LinkedListIndex<T>)
     {
         unsafe {
             match self.try_validate_index(index) {
-                Ok(()) => {
-                    let prev = (*index.list_ptr).prev;
-                    index.list_ptr = prev;
-                    index.node_tag = (*prev).tag;
-                },
-                Err(IndexError::OutOfBounds) => {},
+                Ok(()) => *index = self.index_of((*index.list_ptr).prev),
+                Err(IndexError::OutOfBounds) if index.list_ptr == self.last => *index = self.rbegin(),
+                Err(IndexError::OutOfBounds) => (),
                 other_err => other_err.unwrap()
             }
         }
@@ -357,6 +439,11 @@ impl<T> Debug for LinkedList<T>
 
 }
 
+impl<T> Default for LinkedList<T>
+{
+    fn default() -> Self { Self::new() }
+}
+
 impl<T> Clone for LinkedList<T>
     where T: Clone
 {
@@ -0,0 +1,66 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display},
+    num::{NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroUsize}
+};
+
+// Integers that cannot be their maximum value, so that `Option` of them is as large as the integer itself.
+// Values are stored inverted in a `NonZero` integer, which provides the niche.
+macro_rules! non_max {
+    ($($name:ident($t:ty, $nz:ty)),*) => {
+        $(
+            #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+            pub struct $name($nz);
+
+            impl $name
+            {
+                // `None` if `value` is the maximum value of the integer.
+                pub const fn new(value: $t) -> Option<Self>
+                {
+                    match <$nz>::new(!value) {
+                        Some(x) => Some($name(x)),
+                        None => None
+                    }
+                }
+
+                pub const fn get(self) -> $t
+                {
+                    !self.0.get()
+                }
+            }
+
+            impl PartialOrd for $name
+            {
+                fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+            }
+
+            impl Ord for $name
+            {
+                fn cmp(&self, other: &Self) -> Ordering { self.get().cmp(&other.get()) }
+            }
+
+            impl Debug for $name
+            {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { Debug::fmt(&self.get(), f) }
+            }
+
+            impl Display for $name
+            {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { Display::fmt(&self.get(), f) }
+            }
+
+            impl From<$name> for $t
+            {
+                fn from(value: $name) -> $t { value.get() }
+            }
+        )*
+    };
+}
+
+non_max!(
+    NonMaxUsize(usize, NonZeroUsize),
+    NonMaxU64(u64, NonZeroU64),
+    NonMaxU32(u32, NonZeroU32),
+    NonMaxU16(u16, NonZeroU16),
+    NonMaxU8(u8, NonZeroU8)
+);
@@ -0,0 +1,112 @@
+use std::ops::Range;
+
+use crate::index::ForwardIndex;
+
+// A half-open range of indices, [begin, end).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct IndexRange<I>
+{
+    pub begin: I,
+    pub end: I
+}
+
+impl<I> IndexRange<I>
+{
+    pub fn new(begin: I, end: I) -> Self
+    {
+        Self { begin, end }
+    }
+
+    // The range of every element of `collection`.
+    pub fn all<C>(collection: &C) -> Self
+        where C: ForwardIndex<I> + ?Sized
+    {
+        Self::new(collection.begin(), collection.past_end())
+    }
+}
+
+impl<I> IndexRange<I>
+    where I: PartialEq
+{
+    pub fn is_empty(&self) -> bool
+    {
+        self.begin == self.end
+    }
+}
+
+impl<I> From<Range<I>> for IndexRange<I>
+{
+    fn from(range: Range<I>) -> Self
+    {
+        Self::new(range.start, range.end)
+    }
+}
+
+// Bridges the index traits to Rust iterators, for any `ForwardIndex` collection.
+pub trait IndexIterExt<I>: ForwardIndex<I>
+{
+    // Yields the indices of `range`.
+    fn index_iter<R>(&self, range: R) -> IndexIter<'_, Self, I>
+        where R: Into<IndexRange<I>>
+    {
+        IndexIter { collection: self, range: range.into() }
+    }
+
+    // Yields the indices of `range`, along with their elements.
+    fn indexed_iter<R>(&self, range: R) -> IndexedIter<'_, Self, I>
+        where R: Into<IndexRange<I>>
+    {
+        IndexedIter { inner: self.index_iter(range) }
+    }
+}
+
+impl<C, I> IndexIterExt<I> for C where C: ForwardIndex<I> + ?Sized {}
+
+pub struct IndexIter<'a, C, I>
+    where C: ?Sized
+{
+    collection: &'a C,
+    range: IndexRange<I>
+}
+
+// Stops at the end of the range, or at the first invalid index, so that a bad `end` cannot make it loop
+// forever.
+impl<'a, C, I> Iterator for IndexIter<'a, C, I>
+    where
+        C: ForwardIndex<I> + ?Sized,
+        I: Copy + PartialEq
+{
+    type Item = I;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        let curr = self.range.begin;
+        if curr == self.range.end || !self.collection.valid(&curr) {
+            return None;
+        }
+
+        self.collection.increment(&mut self.range.begin);
+        Some(curr)
+    }
+}
+
+pub struct IndexedIter<'a, C, I>
+    where C: ?Sized
+{
+    inner: IndexIter<'a, C, I>
+}
+
+impl<'a, C, I> Iterator for IndexedIter<'a, C, I>
+    where
+        C: ForwardIndex<I> + ?Sized,
+        C::Output: 'a,
+        I: Copy + PartialEq
+{
+    type Item = (I, &'a C::Output);
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        let collection = self.inner.collection;
+        self.inner.next().map(|idx| (idx, &collection[idx]))
+    }
+}
@@ -0,0 +1,309 @@
+use std::{
+    fmt::{self, Debug, Formatter},
+    iter::FromIterator,
+    ops::{Index, IndexMut, Deref, DerefMut}
+};
+
+use uuid::Uuid;
+
+use crate::{
+    index::{IndexExt, ForwardIndex, BackwardIndex, SwapIndex},
+    standard_impls::{step_forward, step_backward, REND}
+};
+
+// A Vec that can only be indexed by indices it has issued. Every index carries the tag of its vector,
+// and the generation it was created at; operations that shift or drop elements start a new generation,
+// invalidating all outstanding indices. Pushing does not, since no existing element moves.
+pub struct SafeVec<T>
+{
+    inner: Vec<T>,
+    tag: Uuid,
+    generation: u64
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SafeVecIndex
+{
+    index: usize,
+    tag: Uuid,
+    generation: u64
+}
+
+#[derive(Debug)]
+pub enum IndexError
+{
+    TagMismatch,
+    Invalidated,
+    OutOfBounds
+}
+
+impl fmt::Display for IndexError
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result
+    {
+        f.write_str(match self {
+            IndexError::TagMismatch => "Index from another collection on nicole::SafeVec",
+            IndexError::Invalidated => "Index invalidated by a change on nicole::SafeVec",
+            IndexError::OutOfBounds => "Index out of bounds on nicole::SafeVec"
+        })
+    }
+}
+
+impl std::error::Error for IndexError {}
+
+impl<T> SafeVec<T>
+{
+    pub fn new() -> Self
+    {
+        Self::from(Vec::new())
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self
+    {
+        Self::from(Vec::with_capacity(capacity))
+    }
+
+    pub fn into_inner(self) -> Vec<T>
+    {
+        self.inner
+    }
+
+    fn wrap(&self, index: usize) -> SafeVecIndex
+    {
+        SafeVecIndex { index, tag: self.tag, generation: self.generation }
+    }
+
+    fn invalidate(&mut self)
+    {
+        self.generation += 1;
+    }
+
+    // Checks `index` against the positions `0..bound`.
+    fn try_validate_position(&self, index: &SafeVecIndex, bound: usize) -> Result<(), IndexError>
+    {
+        if index.tag != self.tag {
+            Err(IndexError::TagMismatch)
+        }
+        else if index.generation != self.generation {
+            Err(IndexError::Invalidated)
+        }
+        else if index.index >= bound {
+            Err(IndexError::OutOfBounds)
+        }
+        else {
+            Ok(())
+        }
+    }
+
+    fn try_validate_index(&self, index: &SafeVecIndex) -> Result<(), IndexError>
+    {
+        self.try_validate_position(index, self.inner.len())
+    }
+
+    // Panics with the message of the error, as the other checks do.
+    fn validate_position(&self, index: &SafeVecIndex, bound: usize)
+    {
+        if let Err(e) = self.try_validate_position(index, bound) {
+            panic!("{}", e)
+        }
+    }
+
+    fn validate_index(&self, index: &SafeVecIndex)
+    {
+        self.validate_position(index, self.inner.len())
+    }
+
+    // Like `Tagged`, moving an index of another vector panics. Sentinels of the same generation are fine.
+    fn check_tag(&self, index: &SafeVecIndex)
+    {
+        assert!(index.tag == self.tag, "{}", IndexError::TagMismatch);
+    }
+
+    pub fn try_get(&self, index: SafeVecIndex) -> Result<&T, IndexError>
+    {
+        self.try_validate_index(&index)?;
+        Ok(&self.inner[index.index])
+    }
+
+    pub fn try_get_mut(&mut self, index: SafeVecIndex) -> Result<&mut T, IndexError>
+    {
+        self.try_validate_index(&index)?;
+        Ok(&mut self.inner[index.index])
+    }
+
+    pub fn push(&mut self, element: T) -> SafeVecIndex
+    {
+        self.inner.push(element);
+        self.wrap(self.inner.len() - 1)
+    }
+
+    pub fn pop(&mut self) -> Option<T>
+    {
+        let ret = self.inner.pop();
+        if ret.is_some() {
+            self.invalidate();
+        }
+        ret
+    }
+
+    // Inserts before `index`, which may be `past_end()`. Invalidates all indices.
+    pub fn insert(&mut self, index: SafeVecIndex, element: T)
+    {
+        self.validate_position(&index, self.inner.len() + 1);
+        self.inner.insert(index.index, element);
+        self.invalidate();
+    }
+
+    // Invalidates all indices.
+    pub fn remove(&mut self, index: SafeVecIndex) -> T
+    {
+        self.validate_index(&index);
+        let ret = self.inner.remove(index.index);
+        self.invalidate();
+        ret
+    }
+
+    pub fn clear(&mut self)
+    {
+        self.inner.clear();
+        self.invalidate();
+    }
+}
+
+impl<T> From<Vec<T>> for SafeVec<T>
+{
+    fn from(inner: Vec<T>) -> Self
+    {
+        Self { inner, tag: Uuid::new_v4(), generation: 0 }
+    }
+}
+
+// A clone gets a fresh tag; indices of the original do not index the clone.
+impl<T> Clone for SafeVec<T>
+    where T: Clone
+{
+    fn clone(&self) -> Self
+    {
+        Self::from(self.inner.clone())
+    }
+}
+
+impl<T> Default for SafeVec<T>
+{
+    fn default() -> Self { Self::new() }
+}
+
+// Only a slice is exposed, so that the length cannot change behind the generation counter.
+impl<T> Deref for SafeVec<T>
+{
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target { &self.inner }
+}
+
+impl<T> DerefMut for SafeVec<T>
+{
+    fn deref_mut(&mut self) -> &mut Self::Target { &mut self.inner }
+}
+
+impl<T> Index<SafeVecIndex> for SafeVec<T>
+{
+    type Output = T;
+
+    fn index(&self, index: SafeVecIndex) -> &Self::Output
+    {
+        self.validate_index(&index);
+        &self.inner[index.index]
+    }
+}
+
+impl<T> IndexMut<SafeVecIndex> for SafeVec<T>
+{
+    fn index_mut(&mut self, index: SafeVecIndex) -> &mut Self::Output
+    {
+        self.validate_index(&index);
+        &mut self.inner[index.index]
+    }
+}
+
+impl<T> IndexExt<SafeVecIndex> for SafeVec<T>
+{
+    fn valid(&self, index: &SafeVecIndex) -> bool { self.try_validate_index(index).is_ok() }
+}
+
+// Swapping moves no other element, so indices stay valid.
+impl<T> SwapIndex<SafeVecIndex> for SafeVec<T>
+{
+    fn swap_indices(&mut self, a: SafeVecIndex, b: SafeVecIndex)
+    {
+        self.validate_index(&a);
+        self.validate_index(&b);
+        self.inner.swap(a.index, b.index);
+    }
+}
+
+impl<T> ForwardIndex<SafeVecIndex> for SafeVec<T>
+{
+    fn begin(&self) -> SafeVecIndex { self.wrap(0) }
+    fn past_end(&self) -> SafeVecIndex { self.wrap(self.inner.len()) }
+
+    fn increment(&self, idx: &mut SafeVecIndex)
+    {
+        self.check_tag(idx);
+        step_forward(self.inner.len(), &mut idx.index, 1)
+    }
+
+    fn move_forward(&self, idx: &mut SafeVecIndex, distance: usize)
+    {
+        self.check_tag(idx);
+        step_forward(self.inner.len(), &mut idx.index, distance)
+    }
+}
+
+impl<T> BackwardIndex<SafeVecIndex> for SafeVec<T>
+{
+    fn rbegin(&self) -> SafeVecIndex { self.wrap(self.inner.len().wrapping_sub(1)) }
+    fn rend(&self) -> SafeVecIndex { self.wrap(REND) }
+
+    fn decrement(&self, idx: &mut SafeVecIndex)
+    {
+        self.check_tag(idx);
+        step_backward(self.inner.len(), &mut idx.index, 1)
+    }
+
+    fn move_backward(&self, idx: &mut SafeVecIndex, distance: usize)
+    {
+        self.check_tag(idx);
+        step_backward(self.inner.len(), &mut idx.index, distance)
+    }
+}
+
+impl<T> IntoIterator for SafeVec<T>
+{
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        self.inner.into_iter()
+    }
+}
+
+impl<T> Debug for SafeVec<T>
+    where T: Debug
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result
+    {
+        write!(f, "SafeVec ")?;
+        f.debug_list().entries(self.inner.iter()).finish()
+    }
+}
+
+impl<ItemT> FromIterator<ItemT> for SafeVec<ItemT>
+{
+    fn from_iter<IteratorT>(iter: IteratorT) -> Self
+        where IteratorT: IntoIterator<Item = ItemT>
+    {
+        Self::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
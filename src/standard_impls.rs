@@ -1,6 +1,42 @@
 use std::collections::VecDeque;
 
-use crate::index::{IndexExt, BackwardIndex, ForwardIndex};
+use crate::index::{IndexExt, BackwardIndex, ForwardIndex, RandomAccessIndex, SwapIndex};
+
+// Positions of vector-like collections: `len` is past-the-end, and `usize::MAX` is before-begin, so
+// that incrementing it wraps to 0.
+pub(crate) const REND: usize = usize::MAX;
+
+pub(crate) fn step_forward(len: usize, i: &mut usize, distance: usize)
+{
+    if distance > 0 {
+        let start = if *i == REND { 0 } else { (*i).min(len).saturating_add(1) };
+        *i = start.saturating_add(distance - 1).min(len);
+    }
+}
+
+pub(crate) fn step_backward(len: usize, i: &mut usize, distance: usize)
+{
+    if *i != REND {
+        let start = (*i).min(len);
+        *i = if distance > start { REND } else { start - distance };
+    }
+}
+
+fn position(i: usize) -> isize
+{
+    if i == REND { -1 } else { i as isize }
+}
+
+pub(crate) fn distance(a: usize, b: usize) -> isize
+{
+    position(b) - position(a)
+}
+
+pub(crate) fn jump(len: usize, i: &mut usize, distance: isize)
+{
+    let target = position(*i).min(len as isize).saturating_add(distance).clamp(-1, len as isize);
+    *i = if target < 0 { REND } else { target as usize };
+}
 
 impl<T> IndexExt<usize> for Vec<T>
 {
@@ -10,15 +46,28 @@ impl<T> IndexExt<usize> for Vec<T>
 impl<T> ForwardIndex<usize> for Vec<T>
 {
     fn begin(&self) -> usize { 0 }
-    fn increment(&self, i: &mut usize) { *i += 1; }
-    fn move_forward(&self, i: &mut usize, distance: usize) { *i += distance; }
+    fn past_end(&self) -> usize { self.len() }
+    fn increment(&self, i: &mut usize) { step_forward(self.len(), i, 1) }
+    fn move_forward(&self, i: &mut usize, distance: usize) { step_forward(self.len(), i, distance) }
 }
 
 impl<T> BackwardIndex<usize> for Vec<T>
 {
-    fn end(&self) -> usize { self.len().wrapping_sub(1) }
-    fn decrement(&self, i: &mut usize) { *i = i.wrapping_sub(1); }
-    fn move_backward(&self, i: &mut usize, distance: usize) { *i = i.wrapping_sub(distance); }
+    fn rbegin(&self) -> usize { self.len().wrapping_sub(1) }
+    fn rend(&self) -> usize { REND }
+    fn decrement(&self, i: &mut usize) { step_backward(self.len(), i, 1) }
+    fn move_backward(&self, i: &mut usize, distance: usize) { step_backward(self.len(), i, distance) }
+}
+
+impl<T> RandomAccessIndex<usize> for Vec<T>
+{
+    fn distance(&self, a: &usize, b: &usize) -> isize { distance(*a, *b) }
+    fn jump(&self, i: &mut usize, distance: isize) { jump(self.len(), i, distance) }
+}
+
+impl<T> SwapIndex<usize> for Vec<T>
+{
+    fn swap_indices(&mut self, a: usize, b: usize) { self.swap(a, b); }
 }
 
 impl<T> IndexExt<usize> for VecDeque<T>
@@ -29,13 +78,58 @@ impl<T> IndexExt<usize> for VecDeque<T>
 impl<T> ForwardIndex<usize> for VecDeque<T>
 {
     fn begin(&self) -> usize { 0 }
-    fn increment(&self, i: &mut usize) { *i += 1; }
-    fn move_forward(&self, i: &mut usize, distance: usize) { *i += distance; }
+    fn past_end(&self) -> usize { self.len() }
+    fn increment(&self, i: &mut usize) { step_forward(self.len(), i, 1) }
+    fn move_forward(&self, i: &mut usize, distance: usize) { step_forward(self.len(), i, distance) }
 }
 
 impl<T> BackwardIndex<usize> for VecDeque<T>
 {
-    fn end(&self) -> usize { self.len().wrapping_sub(1) }
-    fn decrement(&self, i: &mut usize) { *i = i.wrapping_sub(1); }
-    fn move_backward(&self, i: &mut usize, distance: usize) { *i = i.wrapping_sub(distance); }
+    fn rbegin(&self) -> usize { self.len().wrapping_sub(1) }
+    fn rend(&self) -> usize { REND }
+    fn decrement(&self, i: &mut usize) { step_backward(self.len(), i, 1) }
+    fn move_backward(&self, i: &mut usize, distance: usize) { step_backward(self.len(), i, distance) }
+}
+
+impl<T> RandomAccessIndex<usize> for VecDeque<T>
+{
+    fn distance(&self, a: &usize, b: &usize) -> isize { distance(*a, *b) }
+    fn jump(&self, i: &mut usize, distance: isize) { jump(self.len(), i, distance) }
+}
+
+impl<T> SwapIndex<usize> for VecDeque<T>
+{
+    fn swap_indices(&mut self, a: usize, b: usize) { self.swap(a, b); }
+}
+
+impl<T> IndexExt<usize> for [T]
+{
+    fn valid(&self, i: &usize) -> bool { *i < self.len() }
+}
+
+impl<T> ForwardIndex<usize> for [T]
+{
+    fn begin(&self) -> usize { 0 }
+    fn past_end(&self) -> usize { self.len() }
+    fn increment(&self, i: &mut usize) { step_forward(self.len(), i, 1) }
+    fn move_forward(&self, i: &mut usize, distance: usize) { step_forward(self.len(), i, distance) }
+}
+
+impl<T> BackwardIndex<usize> for [T]
+{
+    fn rbegin(&self) -> usize { self.len().wrapping_sub(1) }
+    fn rend(&self) -> usize { REND }
+    fn decrement(&self, i: &mut usize) { step_backward(self.len(), i, 1) }
+    fn move_backward(&self, i: &mut usize, distance: usize) { step_backward(self.len(), i, distance) }
+}
+
+impl<T> RandomAccessIndex<usize> for [T]
+{
+    fn distance(&self, a: &usize, b: &usize) -> isize { distance(*a, *b) }
+    fn jump(&self, i: &mut usize, distance: isize) { jump(self.len(), i, distance) }
+}
+
+impl<T> SwapIndex<usize> for [T]
+{
+    fn swap_indices(&mut self, a: usize, b: usize) { self.swap(a, b); }
 }
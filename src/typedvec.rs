@@ -3,9 +3,13 @@ use std::{
     ops::{Index, IndexMut, Deref, DerefMut}
 };
 
-use crate::IdLike;
+use crate::{
+    IdLike,
+    index::{IndexExt, ForwardIndex, BackwardIndex, RandomAccessIndex, SwapIndex},
+    standard_impls::{step_forward, step_backward, distance, jump, REND}
+};
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 pub struct TypedVec<K, V>
 {
     inner: Vec<V>,
@@ -26,9 +30,86 @@ impl<K, V> IndexMut<K> for TypedVec<K, V>
     fn index_mut(&mut self, index: K) -> &mut Self::Output { self.get_mut(index).unwrap() }
 }
 
+// Positions as in `standard_impls`, except that `rend()` is `K::null()`, since keys narrower than
+// usize cannot hold `REND`.
+fn position<K>(index: K) -> usize
+    where K: IdLike
+{
+    if index.is_null() { REND } else { index.to_index() }
+}
+
+fn key<K>(position: usize) -> K
+    where K: IdLike
+{
+    if position == REND { K::null() } else { K::from_index(position) }
+}
+
+impl<K, V> IndexExt<K> for TypedVec<K, V>
+    where K: IdLike
+{
+    fn valid(&self, index: &K) -> bool { self.contains(*index) }
+}
+
+impl<K, V> ForwardIndex<K> for TypedVec<K, V>
+    where K: IdLike
+{
+    fn begin(&self) -> K { K::from_index(0) }
+    fn past_end(&self) -> K { K::from_index(self.inner.len()) }
+    fn increment(&self, index: &mut K) { self.move_forward(index, 1) }
+
+    fn move_forward(&self, index: &mut K, distance: usize)
+    {
+        let mut i = position(*index);
+        step_forward(self.inner.len(), &mut i, distance);
+        *index = key(i);
+    }
+}
+
+impl<K, V> BackwardIndex<K> for TypedVec<K, V>
+    where K: IdLike
+{
+    fn rbegin(&self) -> K { key(self.inner.len().wrapping_sub(1)) }
+    fn rend(&self) -> K { K::null() }
+    fn decrement(&self, index: &mut K) { self.move_backward(index, 1) }
+
+    fn move_backward(&self, index: &mut K, distance: usize)
+    {
+        let mut i = position(*index);
+        step_backward(self.inner.len(), &mut i, distance);
+        *index = key(i);
+    }
+}
+
+impl<K, V> RandomAccessIndex<K> for TypedVec<K, V>
+    where K: IdLike
+{
+    fn distance(&self, a: &K, b: &K) -> isize { distance(position(*a), position(*b)) }
+
+    fn jump(&self, index: &mut K, distance: isize)
+    {
+        let mut i = position(*index);
+        jump(self.inner.len(), &mut i, distance);
+        *index = key(i);
+    }
+}
+
+impl<K, V> SwapIndex<K> for TypedVec<K, V>
+    where K: IdLike
+{
+    fn swap_indices(&mut self, a: K, b: K) { self.inner.swap(a.to_index(), b.to_index()) }
+}
+
+// Not derived, since that would need `K: Default`.
+impl<K, V> Default for TypedVec<K, V>
+    where K: IdLike
+{
+    fn default() -> Self { Self::new() }
+}
+
+// Only a slice is exposed, so that the length cannot outgrow the keys behind `push`.
 impl <K, V> Deref for TypedVec<K, V>
 {
-    type Target = Vec<V>;
+    type Target = [V];
 
     fn deref(&self) -> &Self::Target { &self.inner }
 }
@@ -46,34 +127,64 @@ impl<K, V> TypedVec<K, V>
         Self { inner: Vec::new(), phantom: PhantomData }
     }
 
+    // The length is capped so that `past_end()`, the key of index `len()`, is still a key other than
+    // null, which is `rend()`. E.g. a `TypedVec<u8, V>` holds at most 254 values.
+    fn check_len(len: usize)
+    {
+        assert!(K::try_from_index(len).is_some(), "Out of keys for nicole::TypedVec");
+    }
+
+    pub fn push(&mut self, value: V) -> K
+    {
+        Self::check_len(self.inner.len() + 1);
+        self.inner.push(value);
+        K::from_index(self.inner.len() - 1)
+    }
+
+    pub fn pop(&mut self) -> Option<V>
+    {
+        self.inner.pop()
+    }
+
+    pub fn truncate(&mut self, len: usize)
+    {
+        self.inner.truncate(len)
+    }
+
+    pub fn clear(&mut self)
+    {
+        self.inner.clear()
+    }
+
     pub fn get(&self, index: K) -> Option<&V>
     {
-        self.inner.get(index.into())
+        self.inner.get(index.to_index())
     }
 
     pub fn get_mut(&mut self, index: K) -> Option<&mut V>
     {
-        self.inner.get_mut(index.into())
+        self.inner.get_mut(index.to_index())
     }
 
     pub fn contains(&self, index: K) -> bool
     {
-        index.into() < self.inner.len()
+        index.to_index() < self.inner.len()
     }
 
     pub fn iter(&self) -> impl Iterator<Item=(K, &V)>
     {
-        self.inner.iter().enumerate().map(|(i, x)| (i.into(), x))
+        self.inner.iter().enumerate().map(|(i, x)| (K::from_index(i), x))
     }
 
     pub fn iter_mut(&mut self) -> impl Iterator<Item=(K, &mut V)>
     {
-        self.inner.iter_mut().enumerate().map(|(i, x)| (i.into(), x))
+        self.inner.iter_mut().enumerate().map(|(i, x)| (K::from_index(i), x))
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn into_iter(self) -> impl Iterator<Item=(K, V)>
     {
-        self.inner.into_iter().enumerate().map(|(i, x)| (i.into(), x))
+        self.inner.into_iter().enumerate().map(|(i, x)| (K::from_index(i), x))
     }
 }
 
@@ -82,8 +193,9 @@ impl<K, V> TypedVec<K, V>
 {
     pub fn insert(&mut self, index: K, value: V)
     {
-        let uindex = index.into();
+        let uindex = index.to_index();
         if uindex >= self.inner.len() {
+            Self::check_len(uindex + 1);
             self.inner.resize_with(uindex + 1, || Default::default())
         }
         self.inner[uindex] = value;
@@ -0,0 +1,107 @@
+use std::ops::{Index, IndexMut};
+
+use super::{UnrolledVecList, Location, FIRST, LAST};
+use crate::index::{IndexExt, ForwardIndex, BackwardIndex, SwapIndex};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct UnrolledIndex
+{
+    pub(super) handle: usize
+}
+
+impl<T> IndexExt<UnrolledIndex> for UnrolledVecList<T>
+{
+    fn valid(&self, idx: &UnrolledIndex) -> bool { self.occupied(idx.handle) }
+}
+
+impl<T> SwapIndex<UnrolledIndex> for UnrolledVecList<T>
+{
+    fn swap_indices(&mut self, a: UnrolledIndex, b: UnrolledIndex) { self.swap(a, b) }
+}
+
+impl<T> ForwardIndex<UnrolledIndex> for UnrolledVecList<T>
+{
+    fn begin(&self) -> UnrolledIndex
+    {
+        let first = self.chunks[FIRST].next;
+        UnrolledIndex { handle: if first == LAST { LAST } else { self.chunks[first].handles[0] } }
+    }
+
+    fn past_end(&self) -> UnrolledIndex { UnrolledIndex { handle: LAST } }
+
+    fn increment(&self, idx: &mut UnrolledIndex)
+    {
+        if idx.handle == FIRST {
+            *idx = self.begin();
+            return;
+        }
+
+        let Location { chunk, offset } = self.locations[idx.handle];
+        if chunk == LAST {
+            return;
+        }
+
+        let handles = &self.chunks[chunk].handles;
+        idx.handle = if offset + 1 < handles.len() {
+            handles[offset + 1]
+        }
+        else {
+            let next = self.chunks[chunk].next;
+            if next == LAST { LAST } else { self.chunks[next].handles[0] }
+        };
+    }
+}
+
+impl<T> BackwardIndex<UnrolledIndex> for UnrolledVecList<T>
+{
+    fn rbegin(&self) -> UnrolledIndex
+    {
+        let last = self.chunks[LAST].prev;
+        UnrolledIndex { handle: if last == FIRST { FIRST } else { *self.chunks[last].handles.last().unwrap() } }
+    }
+
+    fn rend(&self) -> UnrolledIndex { UnrolledIndex { handle: FIRST } }
+
+    fn decrement(&self, idx: &mut UnrolledIndex)
+    {
+        if idx.handle == LAST {
+            *idx = self.rbegin();
+            return;
+        }
+
+        let Location { chunk, offset } = self.locations[idx.handle];
+        if chunk == FIRST {
+            return;
+        }
+
+        idx.handle = if offset > 0 {
+            self.chunks[chunk].handles[offset - 1]
+        }
+        else {
+            let prev = self.chunks[chunk].prev;
+            if prev == FIRST { FIRST } else { *self.chunks[prev].handles.last().unwrap() }
+        };
+    }
+}
+
+impl<T> Index<UnrolledIndex> for UnrolledVecList<T>
+{
+    type Output = T;
+
+    fn index(&self, index: UnrolledIndex) -> &Self::Output
+    {
+        self.check(index.handle);
+        let Location { chunk, offset } = self.locations[index.handle];
+        &self.chunks[chunk].elems[offset]
+    }
+}
+
+impl<T> IndexMut<UnrolledIndex> for UnrolledVecList<T>
+{
+    fn index_mut(&mut self, index: UnrolledIndex) -> &mut Self::Output
+    {
+        self.check(index.handle);
+        let Location { chunk, offset } = self.locations[index.handle];
+        &mut self.chunks[chunk].elems[offset]
+    }
+}
@@ -0,0 +1,66 @@
+use std::slice;
+
+use super::{UnrolledVecList, FIRST, LAST};
+
+// Walks each chunk as a slice, only following links between chunks.
+pub struct Iter<'a, T>
+{
+    list: &'a UnrolledVecList<T>,
+    chunk: usize,
+    elems: slice::Iter<'a, T>
+}
+
+impl<'a, T> Iter<'a, T>
+{
+    pub fn new(list: &'a UnrolledVecList<T>) -> Self
+    {
+        Self { list, chunk: FIRST, elems: [].iter() }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T>
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        loop {
+            if let Some(elem) = self.elems.next() {
+                return Some(elem);
+            }
+            if self.chunk == LAST {
+                return None;
+            }
+            self.chunk = self.list.chunks[self.chunk].next;
+            self.elems = self.list.chunks[self.chunk].elems.iter();
+        }
+    }
+}
+
+pub struct IntoIter<T>
+{
+    list: UnrolledVecList<T>
+}
+
+impl<T> IntoIter<T>
+{
+    pub fn new(list: UnrolledVecList<T>) -> Self
+    {
+        Self { list }
+    }
+}
+
+impl<T> Iterator for IntoIter<T>
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        if self.list.is_empty() {
+            None
+        }
+        else {
+            Some(self.list.pop_front())
+        }
+    }
+}
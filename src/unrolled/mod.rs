@@ -0,0 +1,332 @@
+use std::{
+    fmt::{self, Debug, Formatter},
+    iter::FromIterator
+};
+
+mod index;
+mod iter;
+
+pub use self::{
+    index::UnrolledIndex,
+    iter::{Iter, IntoIter}
+};
+
+// Elements live in small contiguous chunks, which are doubly linked like the nodes of a VecList.
+// Since elements move between chunks on splits and merges, indices are handles into `locations`,
+// which get remapped whenever an element moves.
+#[derive(Clone)]
+struct Chunk<T>
+{
+    prev: usize,
+    next: usize,
+    handles: Vec<usize>,
+    elems: Vec<T>
+}
+
+impl<T> Chunk<T>
+{
+    fn new(prev: usize, next: usize) -> Self
+    {
+        Self { prev, next, handles: Vec::new(), elems: Vec::new() }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Location
+{
+    chunk: usize,
+    offset: usize
+}
+
+#[derive(Clone)]
+pub struct UnrolledVecList<T>
+{
+    chunks: Vec<Chunk<T>>,
+    free_chunks: Vec<usize>,
+    locations: Vec<Location>,
+    free_handles: Vec<usize>,
+    len: usize
+}
+
+const INVALID: usize = usize::MAX;
+const FIRST: usize = 0;
+const LAST: usize = 1;
+const CHUNK_SIZE: usize = 32;
+
+impl<T> UnrolledVecList<T>
+{
+    pub fn new() -> Self
+    {
+        Self {
+            chunks: vec![Chunk::new(INVALID, LAST), Chunk::new(FIRST, INVALID)],
+            free_chunks: Vec::new(),
+            locations: vec![Location { chunk: FIRST, offset: 0 }, Location { chunk: LAST, offset: 0 }],
+            free_handles: Vec::new(),
+            len: 0
+        }
+    }
+
+    fn occupied(&self, handle: usize) -> bool
+    {
+        handle > LAST && handle < self.locations.len() && self.locations[handle].chunk != INVALID
+    }
+
+    fn check(&self, handle: usize)
+    {
+        assert!(self.occupied(handle), "Invalid index on nicole::UnrolledVecList");
+    }
+
+    fn new_handle(&mut self, location: Location) -> usize
+    {
+        match self.free_handles.pop() {
+            Some(x) => {
+                self.locations[x] = location;
+                x
+            },
+            None => {
+                self.locations.push(location);
+                self.locations.len() - 1
+            }
+        }
+    }
+
+    // Creates an empty chunk after `prev`.
+    fn new_chunk(&mut self, prev: usize) -> usize
+    {
+        let next = self.chunks[prev].next;
+        let chunk = Chunk::new(prev, next);
+
+        let pos = match self.free_chunks.pop() {
+            Some(x) => {
+                self.chunks[x] = chunk;
+                x
+            },
+            None => {
+                self.chunks.push(chunk);
+                self.chunks.len() - 1
+            }
+        };
+
+        self.chunks[prev].next = pos;
+        self.chunks[next].prev = pos;
+        pos
+    }
+
+    fn free_chunk(&mut self, chunk: usize)
+    {
+        let prev = self.chunks[chunk].prev;
+        let next = self.chunks[chunk].next;
+        self.chunks[prev].next = next;
+        self.chunks[next].prev = prev;
+
+        self.chunks[chunk] = Chunk::new(INVALID, INVALID);
+        self.free_chunks.push(chunk);
+    }
+
+    // Points the handles of `chunk` from offset `from` onwards to their current position.
+    fn remap(&mut self, chunk: usize, from: usize)
+    {
+        for (offset, &handle) in self.chunks[chunk].handles.iter().enumerate().skip(from) {
+            self.locations[handle] = Location { chunk, offset };
+        }
+    }
+
+    // Moves the upper half of a full chunk into a new chunk after it.
+    fn split(&mut self, chunk: usize)
+    {
+        let new = self.new_chunk(chunk);
+        let half = self.chunks[chunk].elems.len() / 2;
+
+        let elems = self.chunks[chunk].elems.split_off(half);
+        let handles = self.chunks[chunk].handles.split_off(half);
+        self.chunks[new].elems = elems;
+        self.chunks[new].handles = handles;
+
+        self.remap(new, 0);
+    }
+
+    // Appends `other` to `chunk`, and frees `other`.
+    fn merge(&mut self, chunk: usize, other: usize)
+    {
+        let from = self.chunks[chunk].elems.len();
+
+        let mut elems = std::mem::take(&mut self.chunks[other].elems);
+        let mut handles = std::mem::take(&mut self.chunks[other].handles);
+        self.chunks[chunk].elems.append(&mut elems);
+        self.chunks[chunk].handles.append(&mut handles);
+
+        self.remap(chunk, from);
+        self.free_chunk(other);
+    }
+
+    fn rebalance(&mut self, chunk: usize)
+    {
+        let len = self.chunks[chunk].elems.len();
+        let prev = self.chunks[chunk].prev;
+        let next = self.chunks[chunk].next;
+
+        if len == 0 {
+            self.free_chunk(chunk);
+        }
+        else if len < CHUNK_SIZE / 2 {
+            if next != LAST && len + self.chunks[next].elems.len() <= CHUNK_SIZE {
+                self.merge(chunk, next);
+            }
+            else if prev != FIRST && len + self.chunks[prev].elems.len() <= CHUNK_SIZE {
+                self.merge(prev, chunk);
+            }
+        }
+    }
+
+    fn _insert(&mut self, before: usize, element: T)
+    {
+        assert!(before == LAST || self.occupied(before), "Invalid index on nicole::UnrolledVecList");
+
+        let Location { chunk, offset } = if before == LAST {
+            let last = self.chunks[LAST].prev;
+            let chunk = if last == FIRST || self.chunks[last].elems.len() == CHUNK_SIZE { self.new_chunk(last) } else { last };
+            Location { chunk, offset: self.chunks[chunk].elems.len() }
+        }
+        else {
+            if self.chunks[self.locations[before].chunk].elems.len() == CHUNK_SIZE {
+                self.split(self.locations[before].chunk);
+            }
+            self.locations[before]
+        };
+
+        let handle = self.new_handle(Location { chunk, offset });
+        self.chunks[chunk].elems.insert(offset, element);
+        self.chunks[chunk].handles.insert(offset, handle);
+        self.remap(chunk, offset + 1);
+        self.len += 1;
+    }
+
+    pub fn insert(&mut self, before: UnrolledIndex, element: T)
+    {
+        self._insert(before.handle, element);
+    }
+
+    fn _remove(&mut self, handle: usize) -> T
+    {
+        self.check(handle);
+
+        let Location { chunk, offset } = self.locations[handle];
+        let ret = self.chunks[chunk].elems.remove(offset);
+        self.chunks[chunk].handles.remove(offset);
+        self.remap(chunk, offset);
+
+        self.locations[handle].chunk = INVALID;
+        self.free_handles.push(handle);
+        self.len -= 1;
+
+        self.rebalance(chunk);
+        ret
+    }
+
+    pub fn remove(&mut self, index: UnrolledIndex) -> T
+    {
+        self._remove(index.handle)
+    }
+
+    pub fn swap(&mut self, a: UnrolledIndex, b: UnrolledIndex)
+    {
+        self.check(a.handle);
+        self.check(b.handle);
+
+        let a = self.locations[a.handle];
+        let b = self.locations[b.handle];
+        if a.chunk == b.chunk {
+            self.chunks[a.chunk].elems.swap(a.offset, b.offset);
+        }
+        else {
+            let (lo, hi) = if a.chunk < b.chunk { (a, b) } else { (b, a) };
+            let (left, right) = self.chunks.split_at_mut(hi.chunk);
+            std::mem::swap(&mut left[lo.chunk].elems[lo.offset], &mut right[0].elems[hi.offset]);
+        }
+    }
+
+    pub fn push_back(&mut self, element: T)
+    {
+        self._insert(LAST, element);
+    }
+
+    pub fn push_front(&mut self, element: T)
+    {
+        let first = self.chunks[FIRST].next;
+        let before = if first == LAST { LAST } else { self.chunks[first].handles[0] };
+        self._insert(before, element);
+    }
+
+    pub fn pop_back(&mut self) -> T
+    {
+        let last = self.chunks[LAST].prev;
+        let handle = *self.chunks[last].handles.last().expect("Empty nicole::UnrolledVecList");
+        self._remove(handle)
+    }
+
+    pub fn pop_front(&mut self) -> T
+    {
+        let first = self.chunks[FIRST].next;
+        let handle = *self.chunks[first].handles.first().expect("Empty nicole::UnrolledVecList");
+        self._remove(handle)
+    }
+
+    pub fn clear(&mut self)
+    {
+        *self = Self::new();
+    }
+
+    pub fn len(&self) -> usize
+    {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        self.len == 0
+    }
+
+    pub fn iter(&self) -> Iter<'_, T>
+    {
+        Iter::new(self)
+    }
+}
+
+impl<T> Default for UnrolledVecList<T>
+{
+    fn default() -> Self { Self::new() }
+}
+
+impl<T> IntoIterator for UnrolledVecList<T>
+{
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T>
+    {
+        IntoIter::new(self)
+    }
+}
+
+impl<T> Debug for UnrolledVecList<T>
+    where T: Debug
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result
+    {
+        write!(f, "UnrolledVecList ")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<ItemT> FromIterator<ItemT> for UnrolledVecList<ItemT>
+{
+    fn from_iter<IteratorT>(iter: IteratorT) -> Self
+        where IteratorT: IntoIterator<Item = ItemT>
+    {
+        let mut list = UnrolledVecList::new();
+        for item in iter {
+            list.push_back(item);
+        }
+        list
+    }
+}
@@ -0,0 +1,68 @@
+use std::ops::{Index, IndexMut};
+
+use super::{VecForwardList, HEAD, END};
+use crate::{
+    index::{IndexExt, ForwardIndex, SwapIndex},
+    veclist::Link
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct VecForwardListIndex<L = usize>
+{
+    index: L
+}
+
+impl<L> VecForwardListIndex<L>
+    where L: Link
+{
+    pub(super) fn new(slot: usize) -> Self { Self { index: L::from_usize(slot) } }
+    pub(super) fn slot(self) -> usize { self.index.to_usize() }
+}
+
+impl<T, L> IndexExt<VecForwardListIndex<L>> for VecForwardList<T, L>
+    where L: Link
+{
+    fn valid(&self, idx: &VecForwardListIndex<L>) -> bool { self.occupied(idx.slot()) }
+}
+
+impl<T, L> SwapIndex<VecForwardListIndex<L>> for VecForwardList<T, L>
+    where L: Link
+{
+    fn swap_indices(&mut self, a: VecForwardListIndex<L>, b: VecForwardListIndex<L>) { self.swap(a, b) }
+}
+
+impl<T, L> ForwardIndex<VecForwardListIndex<L>> for VecForwardList<T, L>
+    where L: Link
+{
+    fn begin(&self) -> VecForwardListIndex<L> { VecForwardListIndex::new(self.elements[HEAD].next()) }
+    fn past_end(&self) -> VecForwardListIndex<L> { VecForwardListIndex::new(END) }
+
+    fn increment(&self, idx: &mut VecForwardListIndex<L>)
+    {
+        if idx.slot() != END {
+            *idx = VecForwardListIndex::new(self.elements[idx.slot()].next())
+        }
+    }
+}
+
+impl<T, L> Index<VecForwardListIndex<L>> for VecForwardList<T, L>
+    where L: Link
+{
+    type Output = T;
+
+    fn index(&self, index: VecForwardListIndex<L>) -> &Self::Output
+    {
+        self.check(index.slot());
+        self.elements[index.slot()].elem.get()
+    }
+}
+
+impl<T, L> IndexMut<VecForwardListIndex<L>> for VecForwardList<T, L>
+    where L: Link
+{
+    fn index_mut(&mut self, index: VecForwardListIndex<L>) -> &mut Self::Output
+    {
+        self.check(index.slot());
+        self.elements[index.slot()].elem.get_mut()
+    }
+}
@@ -0,0 +1,66 @@
+use super::{VecForwardList, END};
+use crate::veclist::Link;
+
+pub struct Iter<'a, T, L = usize>
+    where L: Link
+{
+    list: &'a VecForwardList<T, L>,
+    index: usize
+}
+
+impl<'a, T, L> Iter<'a, T, L>
+    where L: Link
+{
+    pub fn new(list: &'a VecForwardList<T, L>, index: usize) -> Self
+    {
+        Self { list, index }
+    }
+}
+
+impl<'a, T, L> Iterator for Iter<'a, T, L>
+    where L: Link
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        match self.index {
+            END => None,
+            n => {
+                self.index = self.list.elements[n].next();
+                Some(self.list.elements[n].elem.get())
+            }
+        }
+    }
+}
+
+pub struct IntoIter<T, L = usize>
+    where L: Link
+{
+    list: VecForwardList<T, L>
+}
+
+impl<T, L> IntoIter<T, L>
+    where L: Link
+{
+    pub fn new(list: VecForwardList<T, L>) -> Self
+    {
+        Self { list }
+    }
+}
+
+impl<T, L> Iterator for IntoIter<T, L>
+    where L: Link
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        if self.list.is_empty() {
+            None
+        }
+        else {
+            Some(self.list.pop_front())
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use std::{
+    fmt::{self, Debug, Formatter},
+    iter::FromIterator
+};
+
+mod index;
+mod iter;
+
+pub use self::{
+    index::VecForwardListIndex,
+    iter::{Iter, IntoIter}
+};
+
+use crate::veclist::{Link, Slot};
+
+// A vacant node has `next == VACANT`. Unlike VecList, a single link cannot double as the vacancy
+// marker and the free list link, so vacant slots are kept in a (link sized) stack.
+struct Node<T, L>
+{
+    next: L,
+    elem: Slot<T>
+}
+
+impl<T, L> Node<T, L>
+    where L: Link
+{
+    fn new(next: usize, elem: T) -> Self { Self { next: L::from_usize(next), elem: Slot::new(elem) } }
+    fn new_none(next: usize) -> Self { Self { next: L::from_usize(next), elem: Slot::empty() } }
+
+    fn next(&self) -> usize { self.next.to_usize() }
+    fn set_next(&mut self, next: usize) { self.next = L::from_usize(next) }
+}
+
+pub struct VecForwardList<T, L = usize>
+    where L: Link
+{
+    elements: Vec<Node<T, L>>,
+    free: Vec<L>,
+    tail: usize,
+    len: usize
+}
+
+const INVALID: usize = usize::MAX;
+const VACANT: usize = usize::MAX - 1;
+const HEAD: usize = 0;
+const END: usize = 1;
+
+impl<T> VecForwardList<T>
+{
+    pub fn new() -> Self { Self::compact() }
+    pub fn with_capacity(capacity: usize) -> Self { Self::compact_with_capacity(capacity) }
+}
+
+impl<T, L> VecForwardList<T, L>
+    where L: Link
+{
+    pub fn compact() -> Self
+    {
+        Self::compact_with_capacity(0)
+    }
+
+    pub fn compact_with_capacity(capacity: usize) -> Self
+    {
+        let mut elements = Vec::with_capacity(capacity + 2);
+        elements.push(Node::new_none(END));
+        elements.push(Node::new_none(INVALID));
+
+        Self { elements, free: Vec::new(), tail: HEAD, len: 0 }
+    }
+
+    pub fn capacity(&self) -> usize { self.elements.capacity() - 2 }
+    pub fn reserve(&mut self, additional: usize) { self.elements.reserve(additional) }
+
+    fn occupied(&self, slot: usize) -> bool
+    {
+        slot > END && slot < self.elements.len() && self.elements[slot].next() != VACANT
+    }
+
+    fn check(&self, slot: usize)
+    {
+        assert!(self.occupied(slot), "Invalid index on nicole::VecForwardList");
+    }
+
+    fn _insert_after(&mut self, prev: usize, element: T)
+    {
+        assert!(prev == HEAD || self.occupied(prev), "Invalid index on nicole::VecForwardList");
+
+        let next = self.elements[prev].next();
+        let elem = Node::new(next, element);
+
+        let pos = match self.free.pop() {
+            Some(x) => {
+                let x = x.to_usize();
+                self.elements[x] = elem;
+                x
+            },
+            None => {
+                assert!(self.elements.len() < L::CAPACITY, "Link capacity exceeded on nicole::VecForwardList");
+                self.elements.push(elem);
+                self.elements.len() - 1
+            }
+        };
+
+        self.elements[prev].set_next(pos);
+        if prev == self.tail {
+            self.tail = pos;
+        }
+        self.len += 1;
+    }
+
+    fn _remove_after(&mut self, prev: usize) -> T
+    {
+        assert!(prev == HEAD || self.occupied(prev), "Invalid index on nicole::VecForwardList");
+
+        let index = self.elements[prev].next();
+        assert!(index != END, "Nothing to remove on nicole::VecForwardList");
+
+        let next = self.elements[index].next();
+        self.elements[prev].set_next(next);
+        if index == self.tail {
+            self.tail = prev;
+        }
+
+        let ret = self.elements[index].elem.take();
+        self.elements[index].set_next(VACANT);
+        self.free.push(L::from_usize(index));
+        self.len -= 1;
+
+        ret
+    }
+
+    // The index before the first element, to insert or remove at the front through `*_after`. Like
+    // `rend` of bidirectional collections, incrementing it yields `begin()`.
+    pub fn before_begin(&self) -> VecForwardListIndex<L>
+    {
+        VecForwardListIndex::new(HEAD)
+    }
+
+    pub fn insert_after(&mut self, prev: VecForwardListIndex<L>, element: T)
+    {
+        self._insert_after(prev.slot(), element);
+    }
+
+    pub fn remove_after(&mut self, prev: VecForwardListIndex<L>) -> T
+    {
+        self._remove_after(prev.slot())
+    }
+
+    pub fn push_front(&mut self, element: T)
+    {
+        self._insert_after(HEAD, element);
+    }
+
+    pub fn push_back(&mut self, element: T)
+    {
+        self._insert_after(self.tail, element);
+    }
+
+    pub fn pop_front(&mut self) -> T
+    {
+        self._remove_after(HEAD)
+    }
+
+    pub fn front(&self) -> Option<&T>
+    {
+        if self.is_empty() { None } else { Some(self.elements[self.elements[HEAD].next()].elem.get()) }
+    }
+
+    pub fn back(&self) -> Option<&T>
+    {
+        if self.is_empty() { None } else { Some(self.elements[self.tail].elem.get()) }
+    }
+
+    pub fn clear(&mut self)
+    {
+        while !self.is_empty() {
+            self.pop_front();
+        }
+        self.elements.truncate(END + 1);
+        self.free.clear();
+    }
+
+    pub fn len(&self) -> usize
+    {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        self.len == 0
+    }
+
+    pub fn swap(&mut self, a: VecForwardListIndex<L>, b: VecForwardListIndex<L>)
+    {
+        let (a, b) = (a.slot(), b.slot());
+        self.check(a);
+        self.check(b);
+
+        if a != b {
+            let x = self.elements[a].elem.take();
+            let y = self.elements[b].elem.take();
+            self.elements[a].elem = Slot::new(y);
+            self.elements[b].elem = Slot::new(x);
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, T, L>
+    {
+        Iter::new(self, self.elements[HEAD].next())
+    }
+}
+
+impl<T, L> Clone for VecForwardList<T, L>
+    where
+        T: Clone,
+        L: Link
+{
+    fn clone(&self) -> Self
+    {
+        let elements = self.elements
+            .iter()
+            .enumerate()
+            .map(|(i, node)| Node {
+                next: node.next,
+                elem: if self.occupied(i) { Slot::new(node.elem.get().clone()) } else { Slot::empty() }
+            })
+            .collect();
+
+        Self { elements, free: self.free.clone(), tail: self.tail, len: self.len }
+    }
+}
+
+#[cfg(feature = "unsafe")]
+impl<T, L> Drop for VecForwardList<T, L>
+    where L: Link
+{
+    fn drop(&mut self)
+    {
+        self.clear();
+    }
+}
+
+impl<T, L> Default for VecForwardList<T, L>
+    where L: Link
+{
+    fn default() -> Self { Self::compact() }
+}
+
+impl<T, L> IntoIterator for VecForwardList<T, L>
+    where L: Link
+{
+    type Item = T;
+    type IntoIter = IntoIter<T, L>;
+
+    fn into_iter(self) -> IntoIter<T, L>
+    {
+        IntoIter::new(self)
+    }
+}
+
+impl<T, L> Debug for VecForwardList<T, L>
+    where
+        T: Debug,
+        L: Link
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result
+    {
+        write!(f, "VecForwardList ")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<ItemT, L> FromIterator<ItemT> for VecForwardList<ItemT, L>
+    where L: Link
+{
+    fn from_iter<IteratorT>(iter: IteratorT) -> Self
+        where IteratorT: IntoIterator<Item = ItemT>
+    {
+        let mut list = VecForwardList::compact();
+        for item in iter {
+            list.push_back(item);
+        }
+        list
+    }
+}
@@ -0,0 +1,215 @@
+use std::{
+    iter::FromIterator,
+    ops::{Deref, DerefMut, Index, IndexMut}
+};
+
+use super::{VecList, VecListIndex, Link, FIRST, LAST};
+use crate::index::{IndexExt, ForwardIndex, BackwardIndex, SwapIndex};
+
+// A VecList whose indices wrap around: incrementing the last element yields the first one, and
+// decrementing the first element yields the last one. All VecList methods are available through Deref.
+//
+// The sentinels behave as in any other collection (`rend()` increments to `begin()`, `past_end()`
+// decrements to `rbegin()`, and both saturate), but a non-empty list never reaches them by moving, so a
+// `[begin(), past_end())` loop does not terminate; use `iter_from` instead.
+#[derive(Clone, Debug)]
+pub struct CircularVecList<T, L = usize>
+    where L: Link
+{
+    inner: VecList<T, L>
+}
+
+impl<T> CircularVecList<T>
+{
+    pub fn new() -> Self { Self { inner: VecList::new() } }
+}
+
+impl<T, L> CircularVecList<T, L>
+    where L: Link
+{
+    pub fn into_inner(self) -> VecList<T, L> { self.inner }
+
+    fn wrap_next(&self, slot: usize) -> usize
+    {
+        match self.inner.elements[slot].next() {
+            LAST => self.inner.elements[FIRST].next(),
+            n => n
+        }
+    }
+
+    fn wrap_prev(&self, slot: usize) -> usize
+    {
+        match self.inner.elements[slot].prev() {
+            FIRST => self.inner.elements[LAST].prev(),
+            n => n
+        }
+    }
+
+    // Moves `idx` by `distance` positions, going around at most once.
+    fn rotate(&self, idx: &mut VecListIndex<L>, distance: usize, forward: bool)
+    {
+        let len = self.inner.len();
+        if len == 0 || !self.inner.occupied(idx.slot()) {
+            return;
+        }
+
+        let distance = distance % len;
+
+        if self.inner.ranks.is_some() {
+            let position = self.inner.position(*idx);
+            let target = if forward { (position + distance) % len } else { (position + len - distance) % len };
+            *idx = self.inner.nth(target);
+        }
+        else if forward {
+            for _ in 0..distance { idx.set_slot(self.wrap_next(idx.slot())) }
+        }
+        else {
+            for _ in 0..distance { idx.set_slot(self.wrap_prev(idx.slot())) }
+        }
+    }
+
+    // Iterates once around the list, starting at `start`.
+    pub fn iter_from(&self, start: VecListIndex<L>) -> CircularIter<'_, T, L>
+    {
+        let remaining = if self.inner.occupied(start.slot()) { self.inner.len() } else { 0 };
+        CircularIter { list: self, index: start.slot(), remaining }
+    }
+}
+
+impl<T, L> Default for CircularVecList<T, L>
+    where L: Link
+{
+    fn default() -> Self { Self { inner: VecList::compact() } }
+}
+
+impl<T, L> From<VecList<T, L>> for CircularVecList<T, L>
+    where L: Link
+{
+    fn from(inner: VecList<T, L>) -> Self { Self { inner } }
+}
+
+impl<T, L> Deref for CircularVecList<T, L>
+    where L: Link
+{
+    type Target = VecList<T, L>;
+
+    fn deref(&self) -> &Self::Target { &self.inner }
+}
+
+impl<T, L> DerefMut for CircularVecList<T, L>
+    where L: Link
+{
+    fn deref_mut(&mut self) -> &mut Self::Target { &mut self.inner }
+}
+
+impl<T, L> IndexExt<VecListIndex<L>> for CircularVecList<T, L>
+    where L: Link
+{
+    fn valid(&self, idx: &VecListIndex<L>) -> bool { self.inner.valid(idx) }
+}
+
+impl<T, L> SwapIndex<VecListIndex<L>> for CircularVecList<T, L>
+    where L: Link
+{
+    fn swap_indices(&mut self, a: VecListIndex<L>, b: VecListIndex<L>) { self.inner.swap(a, b) }
+}
+
+impl<T, L> ForwardIndex<VecListIndex<L>> for CircularVecList<T, L>
+    where L: Link
+{
+    fn begin(&self) -> VecListIndex<L> { self.inner.begin() }
+    fn past_end(&self) -> VecListIndex<L> { self.inner.past_end() }
+
+    fn increment(&self, idx: &mut VecListIndex<L>)
+    {
+        if idx.slot() != LAST {
+            idx.set_slot(self.wrap_next(idx.slot()))
+        }
+    }
+
+    fn move_forward(&self, idx: &mut VecListIndex<L>, distance: usize)
+    {
+        if idx.slot() == FIRST && distance > 0 {
+            *idx = self.inner.begin();
+            self.rotate(idx, distance - 1, true)
+        }
+        else {
+            self.rotate(idx, distance, true)
+        }
+    }
+}
+
+impl<T, L> BackwardIndex<VecListIndex<L>> for CircularVecList<T, L>
+    where L: Link
+{
+    fn rbegin(&self) -> VecListIndex<L> { self.inner.rbegin() }
+    fn rend(&self) -> VecListIndex<L> { self.inner.rend() }
+
+    fn decrement(&self, idx: &mut VecListIndex<L>)
+    {
+        if idx.slot() != FIRST {
+            idx.set_slot(self.wrap_prev(idx.slot()))
+        }
+    }
+
+    fn move_backward(&self, idx: &mut VecListIndex<L>, distance: usize)
+    {
+        if idx.slot() == LAST && distance > 0 {
+            *idx = self.inner.rbegin();
+            self.rotate(idx, distance - 1, false)
+        }
+        else {
+            self.rotate(idx, distance, false)
+        }
+    }
+}
+
+impl<T, L> Index<VecListIndex<L>> for CircularVecList<T, L>
+    where L: Link
+{
+    type Output = T;
+
+    fn index(&self, index: VecListIndex<L>) -> &Self::Output { &self.inner[index] }
+}
+
+impl<T, L> IndexMut<VecListIndex<L>> for CircularVecList<T, L>
+    where L: Link
+{
+    fn index_mut(&mut self, index: VecListIndex<L>) -> &mut Self::Output { &mut self.inner[index] }
+}
+
+impl<ItemT, L> FromIterator<ItemT> for CircularVecList<ItemT, L>
+    where L: Link
+{
+    fn from_iter<IteratorT>(iter: IteratorT) -> Self
+        where IteratorT: IntoIterator<Item = ItemT>
+    {
+        Self { inner: iter.into_iter().collect() }
+    }
+}
+
+pub struct CircularIter<'a, T, L = usize>
+    where L: Link
+{
+    list: &'a CircularVecList<T, L>,
+    index: usize,
+    remaining: usize
+}
+
+impl<'a, T, L> Iterator for CircularIter<'a, T, L>
+    where L: Link
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let ret = self.list.inner.elements[self.index].elem.get();
+        self.index = self.list.wrap_next(self.index);
+        self.remaining -= 1;
+        Some(ret)
+    }
+}
@@ -1,45 +1,95 @@
 use std::ops::{Index, IndexMut};
 
-use super::{VecList, FIRST, LAST, INVALID, ERROR_MSG};
-use crate::index::{IndexExt, ForwardIndex, BackwardIndex};
+use super::{VecList, Link, FIRST, LAST};
+use crate::index::{IndexExt, ForwardIndex, BackwardIndex, SwapIndex};
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
-pub struct VecListIndex
+pub struct VecListIndex<L = usize>
 {
-    pub(super) index: usize
+    index: L
 }
 
-impl<T> IndexExt<VecListIndex> for VecList<T>
+impl<L> VecListIndex<L>
+    where L: Link
 {
-    fn valid(&self, idx: &VecListIndex) -> bool { idx.index > LAST && idx.index != INVALID }
+    pub(super) fn new(slot: usize) -> Self { Self { index: L::from_usize(slot) } }
+    pub(super) fn slot(self) -> usize { self.index.to_usize() }
+    pub(super) fn set_slot(&mut self, slot: usize) { self.index = L::from_usize(slot) }
 }
 
-impl<T> ForwardIndex<VecListIndex> for VecList<T>
+impl<T, L> IndexExt<VecListIndex<L>> for VecList<T, L>
+    where L: Link
 {
-    fn begin(&self) -> VecListIndex { VecListIndex { index: self.elements[FIRST].next } }
-    fn increment(&self, idx: &mut VecListIndex) { idx.index = self.elements[idx.index].next; }
+    fn valid(&self, idx: &VecListIndex<L>) -> bool { self.occupied(idx.slot()) }
 }
 
-impl<T> BackwardIndex<VecListIndex> for VecList<T>
+impl<T, L> SwapIndex<VecListIndex<L>> for VecList<T, L>
+    where L: Link
 {
-    fn end(&self) -> VecListIndex { VecListIndex { index: self.elements[LAST].prev } }
-    fn decrement(&self, idx: &mut VecListIndex) { idx.index = self.elements[idx.index].prev; }
+    fn swap_indices(&mut self, a: VecListIndex<L>, b: VecListIndex<L>) { self.swap(a, b) }
 }
 
+impl<T, L> ForwardIndex<VecListIndex<L>> for VecList<T, L>
+    where L: Link
+{
+    fn begin(&self) -> VecListIndex<L> { VecListIndex::new(self.elements[FIRST].next()) }
+    fn past_end(&self) -> VecListIndex<L> { VecListIndex::new(LAST) }
+
+    fn increment(&self, idx: &mut VecListIndex<L>)
+    {
+        if idx.slot() != LAST {
+            idx.set_slot(self.elements[idx.slot()].next());
+        }
+    }
+
+    fn move_forward(&self, idx: &mut VecListIndex<L>, distance: usize)
+    {
+        match self.ranks {
+            Some(_) => self.jump(idx, distance.min(isize::MAX as usize) as isize),
+            None => for _ in 0..distance { self.increment(idx) }
+        }
+    }
+}
+
+impl<T, L> BackwardIndex<VecListIndex<L>> for VecList<T, L>
+    where L: Link
+{
+    fn rbegin(&self) -> VecListIndex<L> { VecListIndex::new(self.elements[LAST].prev()) }
+    fn rend(&self) -> VecListIndex<L> { VecListIndex::new(FIRST) }
+
+    fn decrement(&self, idx: &mut VecListIndex<L>)
+    {
+        if idx.slot() != FIRST {
+            idx.set_slot(self.elements[idx.slot()].prev());
+        }
+    }
+
+    fn move_backward(&self, idx: &mut VecListIndex<L>, distance: usize)
+    {
+        match self.ranks {
+            Some(_) => self.jump(idx, -(distance.min(isize::MAX as usize) as isize)),
+            None => for _ in 0..distance { self.decrement(idx) }
+        }
+    }
+}
 
-impl<T> Index<VecListIndex> for VecList<T>
+impl<T, L> Index<VecListIndex<L>> for VecList<T, L>
+    where L: Link
 {
     type Output = T;
-    fn index(&self, index: VecListIndex) -> &Self::Output
+    fn index(&self, index: VecListIndex<L>) -> &Self::Output
     {
-        self.elements[index.index].elem.as_ref().expect(ERROR_MSG)
+        self.check(index.slot());
+        self.elements[index.slot()].elem.get()
     }
 }
 
-impl<T> IndexMut<VecListIndex> for VecList<T>
+impl<T, L> IndexMut<VecListIndex<L>> for VecList<T, L>
+    where L: Link
 {
-    fn index_mut(&mut self, index: VecListIndex) -> &mut Self::Output
+    fn index_mut(&mut self, index: VecListIndex<L>) -> &mut Self::Output
     {
-        self.elements[index.index].elem.as_mut().expect(ERROR_MSG)
+        self.check(index.slot());
+        self.elements[index.slot()].elem.get_mut()
     }
 }
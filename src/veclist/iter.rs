@@ -1,24 +1,29 @@
 use super::{INVALID, LAST};
 
-use super::{VecList, ERROR_MSG};
+use super::{VecList, Link};
 
-pub struct Iter<'a, T>
-    where T: 'a
+pub struct Iter<'a, T, L = usize>
+    where
+        T: 'a,
+        L: Link
 {
-    veclist: &'a VecList<T>,
+    veclist: &'a VecList<T, L>,
     index: usize
 }
 
-impl<'a, T> Iter<'a, T>
+impl<'a, T, L> Iter<'a, T, L>
+    where L: Link
 {
-    pub fn new(veclist: &'a VecList<T>, index: usize) -> Self
+    pub fn new(veclist: &'a VecList<T, L>, index: usize) -> Self
     {
         Self { veclist, index }
     }
 }
 
-impl<'a, T> Iterator for Iter<'a, T>
-    where T: 'a
+impl<'a, T, L> Iterator for Iter<'a, T, L>
+    where
+        T: 'a,
+        L: Link
 {
     type Item = &'a T;
     fn next(&mut self) -> Option<Self::Item>
@@ -26,33 +31,38 @@ impl<'a, T> Iterator for Iter<'a, T>
         match self.index {
             INVALID | LAST => None,
             n => {
-                self.index = self.veclist.elements[n].next; 
-                Some(self.veclist.elements[n].elem.as_ref().expect(ERROR_MSG))
+                self.index = self.veclist.elements[n].next(); 
+                Some(self.veclist.elements[n].elem.get())
             }
         }
     }
 }
 
 #[cfg(feature = "unsafe")]
-pub struct IterMut<'a, T>
-    where T: 'a
+pub struct IterMut<'a, T, L = usize>
+    where
+        T: 'a,
+        L: Link
 {
-    veclist: &'a mut VecList<T>,
+    veclist: &'a mut VecList<T, L>,
     index: usize
 }
 
 #[cfg(feature = "unsafe")]
-impl<'a, T> IterMut<'a, T>
+impl<'a, T, L> IterMut<'a, T, L>
+    where L: Link
 {
-    pub fn new(veclist: &'a mut VecList<T>, index: usize) -> Self
+    pub fn new(veclist: &'a mut VecList<T, L>, index: usize) -> Self
     {
         Self { veclist, index }
     }
 }
 
 #[cfg(feature = "unsafe")]
-impl<'a, T> Iterator for IterMut<'a, T>
-    where T: 'a
+impl<'a, T, L> Iterator for IterMut<'a, T, L>
+    where
+        T: 'a,
+        L: Link
 {
     type Item = &'a mut T;
     fn next<'b>(&'b mut self) -> Option<Self::Item>
@@ -61,8 +71,8 @@ impl<'a, T> Iterator for IterMut<'a, T>
             match self.index {
                 INVALID | LAST => None,
                 n => {
-                    self.index = self.veclist.elements[n].next;
-                    let ret = self.veclist.elements[n].elem.as_mut().expect(ERROR_MSG);
+                    self.index = self.veclist.elements[n].next();
+                    let ret = self.veclist.elements[n].elem.get_mut();
                     Some(std::mem::transmute::<&'b mut T, &'a mut T>(ret))
                 }
             }
@@ -70,50 +80,54 @@ impl<'a, T> Iterator for IterMut<'a, T>
     }
 }
 
-pub struct IntoIter<T>
+pub struct IntoIter<T, L = usize>
+    where L: Link
 {
-    veclist: VecList<T>,
-    index: usize
+    veclist: VecList<T, L>
 }
 
-impl<T> IntoIter<T>
+impl<T, L> IntoIter<T, L>
+    where L: Link
 {
-    pub fn new(veclist: VecList<T>, index: usize) -> Self
+    pub fn new(veclist: VecList<T, L>) -> Self
     {
-        Self { veclist, index }
+        Self { veclist }
     }
 }
 
-impl<T> Iterator for IntoIter<T>
+impl<T, L> Iterator for IntoIter<T, L>
+    where L: Link
 {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item>
     {
-        match self.index {
-            INVALID | LAST => None,
-            n => {
-                self.index = self.veclist.elements[n].next;
-                Some(self.veclist.elements[n].elem.take().expect(ERROR_MSG))
-            }
+        if self.veclist.is_empty() {
+            None
+        }
+        else {
+            Some(self.veclist.pop_front())
         }
     }
 }
 
-pub struct DrainIter<'a, T>
+pub struct DrainIter<'a, T, L = usize>
+    where L: Link
 {
-    veclist: &'a mut VecList<T>,
+    veclist: &'a mut VecList<T, L>,
     index: usize
 }
 
-impl<'a, T> DrainIter<'a, T>
+impl<'a, T, L> DrainIter<'a, T, L>
+    where L: Link
 {
-    pub fn new(veclist: &'a mut VecList<T>, index: usize) -> Self
+    pub fn new(veclist: &'a mut VecList<T, L>, index: usize) -> Self
     {
         Self { veclist, index }
     }
 }
 
-impl<'a, T> Iterator for DrainIter<'a, T>
+impl<'a, T, L> Iterator for DrainIter<'a, T, L>
+    where L: Link
 {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item>
@@ -121,16 +135,17 @@ impl<'a, T> Iterator for DrainIter<'a, T>
         match self.index {
             INVALID | LAST => None,
             n => {
-                self.index = self.veclist.elements[n].next;
-                self.veclist.elements[n].next = INVALID;
-                self.veclist.elements[n].prev = INVALID;
-                Some(self.veclist.elements[n].elem.take().expect(ERROR_MSG))
+                self.index = self.veclist.elements[n].next();
+                let ret = self.veclist.elements[n].elem.take();
+                self.veclist.release(n);
+                Some(ret)
             }
         }
     }
 }
 
-impl<'a, T> Drop for DrainIter<'a, T>
+impl<'a, T, L> Drop for DrainIter<'a, T, L>
+    where L: Link
 {
     fn drop(&mut self)
     {
@@ -0,0 +1,56 @@
+use std::fmt::Debug;
+
+// Integer type used for the prev/next links of a VecList. Narrower links shrink every node, at the
+// cost of a lower maximum number of slots. The two largest values of the type are reserved for the
+// invalid and vacant markers.
+//
+// Sealed: with the `unsafe` feature, whether a node holds an element is read from its links, so an
+// outside implementation that mangled the markers could make safe code read a dropped element.
+pub trait Link: sealed::Sealed + Copy + Eq + Debug
+{
+    const CAPACITY: usize;
+
+    fn from_usize(x: usize) -> Self;
+    fn to_usize(self) -> usize;
+}
+
+macro_rules! impl_link {
+    ($($t:ty),*) => {
+        $(
+            impl Link for $t
+            {
+                const CAPACITY: usize = <$t>::MAX as usize - 1;
+
+                fn from_usize(x: usize) -> Self
+                {
+                    match usize::MAX - x {
+                        marker @ 0..=1 => <$t>::MAX - marker as $t,
+                        _ => {
+                            debug_assert!(x < Self::CAPACITY);
+                            x as $t
+                        }
+                    }
+                }
+
+                fn to_usize(self) -> usize
+                {
+                    match <$t>::MAX - self {
+                        marker @ 0..=1 => usize::MAX - marker as usize,
+                        _ => self as usize
+                    }
+                }
+            }
+        )*
+    }
+}
+
+mod sealed
+{
+    pub trait Sealed {}
+
+    impl Sealed for u16 {}
+    impl Sealed for u32 {}
+    impl Sealed for usize {}
+}
+
+impl_link!(u16, u32, usize);
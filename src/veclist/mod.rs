@@ -1,55 +1,89 @@
 use std::{
+    cmp::Ordering,
     fmt::{self, Debug, Formatter},
-    usize,
     ops::{Bound, RangeBounds},
     iter::FromIterator
 };
     
+mod circular;
 mod iter;
 mod index;
+mod link;
+mod order;
+mod pool;
+mod ranks;
+mod slot;
 
 pub use self::{
+    circular::{CircularVecList, CircularIter},
     iter::{Iter, IntoIter, DrainIter},
-    index::VecListIndex
+    index::VecListIndex,
+    link::Link,
+    pool::{VecListPool, PoolList, PoolIter}
 };
 
-#[derive(Clone)]
-struct Node<T>
+use self::{order::OrderLabels, ranks::Ranks};
+pub(crate) use self::slot::Slot;
+
+// A vacant node has `prev == VACANT`, and its `next` points to the next vacant node.
+struct Node<T, L = usize>
 {
-    pub prev: usize,
-    pub next: usize,
-    pub elem: Option<T>
+    prev: L,
+    next: L,
+    elem: Slot<T>
 }
 
-impl<T> Node<T>
+impl<T, L> Node<T, L>
+    where L: Link
 {
-    fn new(prev: usize, next: usize, elem: T) -> Self { Self { prev, next, elem: Some(elem) } }
-    fn new_none(prev: usize, next: usize) -> Self { Self { prev, next, elem: None } }
+    fn new(prev: usize, next: usize, elem: T) -> Self
+    {
+        Self { prev: L::from_usize(prev), next: L::from_usize(next), elem: Slot::new(elem) }
+    }
+
+    fn new_none(prev: usize, next: usize) -> Self
+    {
+        Self { prev: L::from_usize(prev), next: L::from_usize(next), elem: Slot::empty() }
+    }
+
+    fn prev(&self) -> usize { self.prev.to_usize() }
+    fn next(&self) -> usize { self.next.to_usize() }
+    fn set_prev(&mut self, prev: usize) { self.prev = L::from_usize(prev) }
+    fn set_next(&mut self, next: usize) { self.next = L::from_usize(next) }
 }
 
-#[derive(Clone)]
-pub struct VecList<T>
+pub struct VecList<T, L = usize>
+    where L: Link
 {
-    elements: Vec<Node<T>>,
-    free: Vec<usize>
+    elements: Vec<Node<T, L>>,
+    free: usize,
+    len: usize,
+    order: Option<OrderLabels>,
+    ranks: Option<Ranks>
 }
 
 const INVALID: usize = usize::MAX;
+const VACANT: usize = usize::MAX - 1;
 const FIRST: usize = 0;
 const LAST: usize = 1;
-const ERROR_MSG: &'static str = "Internal error on nicole::VecList";
+const ERROR_MSG: &str = "Internal error on nicole::VecList";
 
 impl<T> VecList<T>
 {
-    pub fn new() -> Self
+    pub fn new() -> Self { Self::compact() }
+    pub fn with_capacity(capacity: usize) -> Self { Self::compact_with_capacity(capacity) }
+}
+
+impl<T, L> VecList<T, L>
+    where L: Link
+{
+    // Same as `new`, for lists with narrower links (e.g. `VecList::<T, u32>::compact()`).
+    pub fn compact() -> Self
     {
-        Self {
-            elements: vec![Node::new_none(INVALID, LAST), Node::new_none(FIRST, INVALID)],
-            free: Vec::new()
-        }
+        Self::compact_with_capacity(0)
     }
 
-    pub fn with_capacity(capacity: usize) -> Self
+    pub fn compact_with_capacity(capacity: usize) -> Self
     {
         let mut elements = Vec::with_capacity(capacity + 2);
         elements.push(Node::new_none(INVALID, LAST));
@@ -57,7 +91,10 @@ impl<T> VecList<T>
 
         Self {
             elements,
-            free: Vec::new()
+            free: INVALID,
+            len: 0,
+            order: None,
+            ranks: None
         }
     }
 
@@ -65,113 +102,213 @@ impl<T> VecList<T>
     pub fn reserve(&mut self, additional: usize) { self.elements.reserve(additional) }
     pub fn reserve_exact(&mut self, additional: usize) { self.elements.reserve_exact(additional) }
 
+    // Compacts the elements in list order, dropping all vacant slots. Invalidates all indices.
     pub fn gc(&mut self)
-        where T: Clone
-    {
-        let len = self.elements.len();
-        let free = self.free.len();
-        let mut new_elems: Vec<Node<T>> = Vec::with_capacity(len - free);
-        new_elems.push(self.elements[FIRST].clone());
-        new_elems.push(self.elements[LAST].clone());
-
-        let mut i = FIRST;
-        while i != INVALID {
-            new_elems.push(self.elements[i].clone());
-            i = self.elements[i].next;
+    {
+        let mut new_elems: Vec<Node<T, L>> = Vec::with_capacity(self.len + 2);
+        new_elems.push(Node::new_none(INVALID, LAST));
+        new_elems.push(Node::new_none(FIRST, INVALID));
+
+        let mut i = self.elements[FIRST].next();
+        while i != LAST {
+            new_elems.push(Node::new(INVALID, INVALID, self.elements[i].elem.take()));
+            i = self.elements[i].next();
         }
 
-        new_elems[FIRST].next = LAST + 1;
-        new_elems[LAST].prev = new_elems.len() - 1;
-
+        // All elements have been moved out of the old nodes
         self.elements = new_elems;
-        self.free = Vec::new();
+        self.free = INVALID;
+
+        let nodes: Vec<usize> = (LAST + 1..self.elements.len()).collect();
+        self.relink(&nodes);
+    }
+
+    fn occupied(&self, slot: usize) -> bool
+    {
+        slot > LAST && slot < self.elements.len() && self.elements[slot].prev() != VACANT
+    }
+
+    fn check(&self, slot: usize)
+    {
+        assert!(self.occupied(slot), "Invalid index on nicole::VecList");
+    }
+
+    // Pushes an unlinked node, whose element has already been taken, on the free list.
+    fn release(&mut self, slot: usize)
+    {
+        self.elements[slot].set_prev(VACANT);
+        self.elements[slot].set_next(self.free);
+        self.free = slot;
+    }
+
+    // Enables O(1) `cmp_order` queries, at the cost of a label per node.
+    pub fn enable_order(&mut self)
+    {
+        if self.order.is_none() {
+            self.order = Some(OrderLabels::new(&self.elements));
+        }
+    }
+
+    pub fn cmp_order(&self, a: VecListIndex<L>, b: VecListIndex<L>) -> Ordering
+    {
+        self.check(a.slot());
+        self.check(b.slot());
+        self.order.as_ref()
+            .expect("cmp_order requires enable_order on nicole::VecList")
+            .cmp(a.slot(), b.slot())
+    }
+
+    // Enables O(log n) `nth`, `position` and index movement, at the cost of a tree node per slot.
+    pub fn enable_ranks(&mut self)
+    {
+        if self.ranks.is_none() {
+            self.ranks = Some(Ranks::new(&self.node_indices()));
+        }
+    }
+
+    fn ranks(&self) -> &Ranks
+    {
+        self.ranks.as_ref().expect("Positional access requires enable_ranks on nicole::VecList")
+    }
+
+    // Returns the past-the-end index if `k` is out of bounds.
+    pub fn nth(&self, k: usize) -> VecListIndex<L>
+    {
+        VecListIndex::new(self.ranks().nth(k).unwrap_or(LAST))
+    }
+
+    pub fn position(&self, index: VecListIndex<L>) -> usize
+    {
+        self.check(index.slot());
+        self.ranks().position(index.slot())
+    }
+
+    // Moves `index` by `distance` positions through the rank tree, saturating at the sentinels.
+    fn jump(&self, index: &mut VecListIndex<L>, distance: isize)
+    {
+        let ranks = self.ranks();
+        let len = ranks.len() as isize;
+        let position = match index.slot() {
+            FIRST => -1,
+            LAST => len,
+            n => ranks.position(n) as isize
+        };
+
+        let target = position.saturating_add(distance);
+        index.set_slot(if target < 0 {
+            FIRST
+        }
+        else if target >= len {
+            LAST
+        }
+        else {
+            ranks.nth(target as usize).expect(ERROR_MSG)
+        });
     }
 
     fn _insert(&mut self, next: usize, element: T)
     {
-        assert!(next >= LAST, "Cannot insert before the first element or after the last element of a VecList");
-        let prev = self.elements[next].prev;
+        assert!(next == LAST || self.occupied(next), "Cannot insert before the first element or after the last element of a VecList");
+        let prev = self.elements[next].prev();
 
         let elem = Node::new(prev, next, element);
 
         // Let's see if we can't reuse some memory:
-        let pos = match self.free.pop() {
-            Some(x) => {
-                self.elements[x] = elem;
-                x
-            },
-            None => {
+        let pos = match self.free {
+            INVALID => {
+                assert!(self.elements.len() < L::CAPACITY, "Link capacity exceeded on nicole::VecList");
                 self.elements.push(elem);
                 self.elements.len() - 1
+            },
+            x => {
+                self.free = self.elements[x].next();
+                self.elements[x] = elem;
+                x
             }
         };
 
-        self.elements[prev].next = pos;
-        self.elements[next].prev = pos;
+        self.len += 1;
+        self.elements[prev].set_next(pos);
+        self.elements[next].set_prev(pos);
+
+        if let Some(order) = &mut self.order {
+            order.insert(&self.elements, pos);
+        }
+        if let Some(ranks) = &mut self.ranks {
+            let position = if next == LAST { ranks.len() } else { ranks.position(next) };
+            ranks.insert(pos, position);
+        }
     }
 
-    pub fn insert(&mut self, next: VecListIndex, element: T)
+    pub fn insert(&mut self, next: VecListIndex<L>, element: T)
     {
-        self._insert(next.index, element);
+        self._insert(next.slot(), element);
     }
 
-    pub fn drain(&mut self, range: impl RangeBounds<VecListIndex>) -> DrainIter<T>
+    pub fn drain(&mut self, range: impl RangeBounds<VecListIndex<L>>) -> DrainIter<'_, T, L>
     {
         let start = match range.start_bound() {
-            Bound::Included(x) => x.index,
-            Bound::Excluded(x) => self.elements[x.index].next,
-            Bound::Unbounded => self.elements[FIRST].next,
+            Bound::Included(x) => x.slot(),
+            Bound::Excluded(x) => self.elements[x.slot()].next(),
+            Bound::Unbounded => self.elements[FIRST].next(),
         };
 
         let end = match range.end_bound() {
-            Bound::Included(x) => self.elements[x.index].next,
-            Bound::Excluded(x) => x.index,
+            Bound::Included(x) => self.elements[x.slot()].next(),
+            Bound::Excluded(x) => x.slot(),
             Bound::Unbounded => LAST
         };
 
-        assert!(start > LAST && end != INVALID, "{}", ERROR_MSG);
         if start == end {
             return DrainIter::new(self, INVALID);
         }
+        assert!(self.occupied(start) && (end == LAST || self.occupied(end)), "Invalid index on nicole::VecList");
 
         let mut i = start;
         while i != end && i != LAST {
-            self.free.push(i);
-            i = self.elements[i].next;
+            self.len -= 1;
+            if let Some(ranks) = &mut self.ranks {
+                ranks.remove(i);
+            }
+            i = self.elements[i].next();
         }
 
-        let start_prev = self.elements[start].prev;
-        let i_prev = self.elements[i].prev;
+        let start_prev = self.elements[start].prev();
+        let i_prev = self.elements[i].prev();
 
-        self.elements[start_prev].next = i;
-        self.elements[i].prev = start_prev;
+        self.elements[start_prev].set_next(i);
+        self.elements[i].set_prev(start_prev);
 
-        self.elements[start].prev = INVALID;
-        self.elements[i_prev].next = INVALID;
+        self.elements[start].set_prev(INVALID);
+        self.elements[i_prev].set_next(INVALID);
 
         DrainIter::new(self, start)
     }
 
     fn _remove(&mut self, index: usize) -> T
     {
-        let ret = self.elements[index].elem.take();
-        self.free.push(index);
+        self.check(index);
+
+        if let Some(ranks) = &mut self.ranks {
+            ranks.remove(index);
+        }
 
-        let prev = self.elements[index].prev;
-        let next = self.elements[index].next;
+        let prev = self.elements[index].prev();
+        let next = self.elements[index].next();
 
-        self.elements[prev].next = next;
-        self.elements[next].prev = prev;
+        self.elements[prev].set_next(next);
+        self.elements[next].set_prev(prev);
 
-        self.elements[index].next = INVALID;
-        self.elements[index].prev = INVALID;
+        let ret = self.elements[index].elem.take();
+        self.release(index);
+        self.len -= 1;
 
-        ret.expect(ERROR_MSG)
+        ret
     }
 
-    pub fn remove(&mut self, index: VecListIndex) -> T
+    pub fn remove(&mut self, index: VecListIndex<L>) -> T
     {
-        self._remove(index.index)
+        self._remove(index.slot())
     }
 
     pub fn push_back(&mut self, value: T)
@@ -181,59 +318,193 @@ impl<T> VecList<T>
 
     pub fn push_front(&mut self, value: T)
     {
-        let next = self.elements[FIRST].next;
+        let next = self.elements[FIRST].next();
         self._insert(next, value);
     }
 
     pub fn pop_back(&mut self) -> T
     {
-        let at = self.elements[LAST].prev;
+        let at = self.elements[LAST].prev();
         self._remove(at)
     }
 
     pub fn pop_front(&mut self) -> T
     {
-        let at = self.elements[FIRST].next;
+        let at = self.elements[FIRST].next();
         self._remove(at)
     }
 
     pub fn clear(&mut self)
     {
-        self.elements.clear();
-        self.free.clear();
+        self.drain(..);
+        self.elements.truncate(LAST + 1);
+        self.free = INVALID;
+
+        if let Some(ranks) = &mut self.ranks {
+            ranks.rebuild(&[]);
+        }
     }
 
     pub fn len(&self) -> usize
     {
-        self.elements.len() - self.free.len() - 2
+        self.len
     }
 
-    pub fn iter<'a>(&'a self) -> Iter<'a, T>
+    pub fn is_empty(&self) -> bool
     {
-        Iter::new(self, self.elements[FIRST].next)
+        self.len() == 0
+    }
+
+    fn node_indices(&self) -> Vec<usize>
+    {
+        let mut ret = Vec::with_capacity(self.len);
+        let mut i = self.elements[FIRST].next();
+        while i != LAST {
+            ret.push(i);
+            i = self.elements[i].next();
+        }
+        ret
+    }
+
+    // Elements never move; only links change, so all indices stay valid.
+    fn relink(&mut self, nodes: &[usize])
+    {
+        let mut prev = FIRST;
+        for &i in nodes {
+            self.elements[prev].set_next(i);
+            self.elements[i].set_prev(prev);
+            prev = i;
+        }
+        self.elements[prev].set_next(LAST);
+        self.elements[LAST].set_prev(prev);
+
+        if let Some(order) = &mut self.order {
+            order.relabel_all(&self.elements);
+        }
+        if let Some(ranks) = &mut self.ranks {
+            ranks.rebuild(nodes);
+        }
+    }
+
+    pub fn sort(&mut self)
+        where T: Ord
+    {
+        self.sort_by(|a, b| a.cmp(b));
+    }
+
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+        where
+            K: Ord,
+            F: FnMut(&T) -> K
+    {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    // Stable merge sort over the node indices, followed by a relink.
+    pub fn sort_by<F>(&mut self, mut compare: F)
+        where F: FnMut(&T, &T) -> Ordering
+    {
+        let mut nodes = self.node_indices();
+        let elements = &self.elements;
+        nodes.sort_by(|&a, &b| compare(
+            elements[a].elem.get(),
+            elements[b].elem.get()
+        ));
+        self.relink(&nodes);
+    }
+
+    pub fn reverse(&mut self)
+    {
+        let mut nodes = self.node_indices();
+        nodes.reverse();
+        self.relink(&nodes);
+    }
+
+    pub fn swap(&mut self, a: VecListIndex<L>, b: VecListIndex<L>)
+    {
+        let (a, b) = (a.slot(), b.slot());
+        self.check(a);
+        self.check(b);
+
+        if a != b {
+            let x = self.elements[a].elem.take();
+            let y = self.elements[b].elem.take();
+            self.elements[a].elem = Slot::new(y);
+            self.elements[b].elem = Slot::new(x);
+        }
+    }
+
+    pub fn iter<'a>(&'a self) -> Iter<'a, T, L>
+    {
+        Iter::new(self, self.elements[FIRST].next())
     }
 
     #[cfg(feature = "unsafe")]
-    pub fn iter_mut<'a>(&'a mut self) -> iter::IterMut<'a, T>
+    pub fn iter_mut<'a>(&'a mut self) -> iter::IterMut<'a, T, L>
+    {
+        iter::IterMut::new(self, self.elements[FIRST].next())
+    }
+}
+
+impl<T, L> Clone for VecList<T, L>
+    where
+        T: Clone,
+        L: Link
+{
+    fn clone(&self) -> Self
+    {
+        let elements = self.elements
+            .iter()
+            .enumerate()
+            .map(|(i, node)| Node {
+                prev: node.prev,
+                next: node.next,
+                elem: if self.occupied(i) { Slot::new(node.elem.get().clone()) } else { Slot::empty() }
+            })
+            .collect();
+
+        Self {
+            elements,
+            free: self.free,
+            len: self.len,
+            order: self.order.clone(),
+            ranks: self.ranks.clone()
+        }
+    }
+}
+
+#[cfg(feature = "unsafe")]
+impl<T, L> Drop for VecList<T, L>
+    where L: Link
+{
+    fn drop(&mut self)
     {
-        iter::IterMut::new(self, self.elements[FIRST].next)
+        self.drain(..);
     }
 }
 
-impl<T> IntoIterator for VecList<T>
-    where T: Clone
+impl<T, L> Default for VecList<T, L>
+    where L: Link
+{
+    fn default() -> Self { Self::compact() }
+}
+
+impl<T, L> IntoIterator for VecList<T, L>
+    where L: Link
 {
     type Item = T;
-    type IntoIter = IntoIter<T>;
+    type IntoIter = IntoIter<T, L>;
 
-    fn into_iter(self) -> IntoIter<T>
+    fn into_iter(self) -> IntoIter<T, L>
     {
-        IntoIter::new(self, FIRST)
+        IntoIter::new(self)
     }
 }
 
-impl<T> Debug for VecList<T>
-    where T: Debug
+impl<T, L> Debug for VecList<T, L>
+    where
+        T: Debug,
+        L: Link
 {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result
     {
@@ -251,12 +522,13 @@ impl<T> Debug for VecList<T>
     }
 }
 
-impl<ItemT> FromIterator<ItemT> for VecList<ItemT>
+impl<ItemT, L> FromIterator<ItemT> for VecList<ItemT, L>
+    where L: Link
 {
     fn from_iter<IteratorT>(iter: IteratorT) -> Self
         where IteratorT: IntoIterator<Item = ItemT>
     {
-        let mut list = VecList::new();
+        let mut list = VecList::compact();
         for item in iter {
             list.push_back(item);
         }
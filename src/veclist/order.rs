@@ -0,0 +1,114 @@
+use std::cmp::Ordering;
+
+use super::{Node, Link, FIRST, LAST};
+
+// Labels live in a u64 space; FIRST is pinned to 0 and LAST to u64::MAX. When a new node
+// does not fit between its neighbours, the smallest aligned label range around it that is
+// sparse enough (Bender et al. / Dietz-Sleator) gets relabeled evenly.
+// Comparisons are O(1), insertions are amortized O(log n) relabels.
+const DENSITY: f64 = 1.4;
+
+#[derive(Clone)]
+pub(super) struct OrderLabels
+{
+    labels: Vec<u64>
+}
+
+impl OrderLabels
+{
+    pub fn new<T, L: Link>(elements: &[Node<T, L>]) -> Self
+    {
+        let mut ret = Self { labels: Vec::new() };
+        ret.relabel_all(elements);
+        ret
+    }
+
+    pub fn cmp(&self, a: usize, b: usize) -> Ordering
+    {
+        self.labels[a].cmp(&self.labels[b])
+    }
+
+    pub fn relabel_all<T, L: Link>(&mut self, elements: &[Node<T, L>])
+    {
+        self.labels.resize(elements.len(), 0);
+        self.labels[FIRST] = 0;
+        self.labels[LAST] = u64::MAX;
+
+        let mut count = 0u64;
+        let mut i = elements[FIRST].next();
+        while i != LAST {
+            count += 1;
+            i = elements[i].next();
+        }
+
+        let stride = u64::MAX / (count + 1);
+        let mut label = 0;
+        let mut i = elements[FIRST].next();
+        while i != LAST {
+            label += stride;
+            self.labels[i] = label;
+            i = elements[i].next();
+        }
+    }
+
+    // Must be called after `pos` has been linked into the list.
+    pub fn insert<T, L: Link>(&mut self, elements: &[Node<T, L>], pos: usize)
+    {
+        if self.labels.len() < elements.len() {
+            self.labels.resize(elements.len(), 0);
+        }
+
+        let prev = self.labels[elements[pos].prev()];
+        let next = self.labels[elements[pos].next()];
+
+        if next - prev >= 2 {
+            self.labels[pos] = prev + (next - prev) / 2;
+        }
+        else {
+            self.relabel_around(elements, pos, prev);
+        }
+    }
+
+    fn relabel_around<T, L: Link>(&mut self, elements: &[Node<T, L>], pos: usize, anchor: u64)
+    {
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+
+        for level in 1..=64u32 {
+            let size = 1u128 << level;
+            let lo = (anchor as u128 & !(size - 1)) as u64;
+            let hi = (lo as u128 + size - 1) as u64;
+
+            left.clear();
+            right.clear();
+
+            let mut i = elements[pos].prev();
+            while i != FIRST && self.labels[i] >= lo {
+                left.push(i);
+                i = elements[i].prev();
+            }
+
+            let mut i = elements[pos].next();
+            while i != LAST && self.labels[i] <= hi {
+                right.push(i);
+                i = elements[i].next();
+            }
+
+            let count = left.len() + right.len() + 1;
+            let stride = size / (count as u128 + 1);
+
+            if stride < 2 || count as f64 > (2.0 / DENSITY).powi(level as i32) {
+                continue;
+            }
+
+            let nodes = left.iter().rev().chain(std::iter::once(&pos)).chain(right.iter());
+            for (k, &i) in nodes.enumerate() {
+                self.labels[i] = (lo as u128 + (k as u128 + 1) * stride) as u64;
+            }
+
+            return;
+        }
+
+        panic!("Order labels exhausted on nicole::VecList");
+    }
+}
@@ -0,0 +1,430 @@
+use std::ops::{Index, IndexMut};
+
+use super::{Node, Link, Slot, VecListIndex, INVALID, VACANT};
+use crate::index::{IndexExt, SwapIndex};
+
+// An arena of VecList nodes shared by many lists. Every list owns a pair of sentinel nodes in the
+// pool: the first sentinel has an invalid `prev`, the last sentinel an invalid `next`. Since indices
+// point into the pool, nodes can move between lists without invalidating them.
+pub struct VecListPool<T, L = usize>
+    where L: Link
+{
+    elements: Vec<Node<T, L>>,
+    free: usize
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PoolList<L = usize>
+{
+    first: L,
+    last: L
+}
+
+impl<L> PoolList<L>
+    where L: Link
+{
+    fn first(self) -> usize { self.first.to_usize() }
+    fn last(self) -> usize { self.last.to_usize() }
+}
+
+impl<T> VecListPool<T>
+{
+    pub fn new() -> Self { Self::compact() }
+}
+
+impl<T, L> VecListPool<T, L>
+    where L: Link
+{
+    pub fn compact() -> Self
+    {
+        Self { elements: Vec::new(), free: INVALID }
+    }
+
+    fn alloc(&mut self, node: Node<T, L>) -> usize
+    {
+        match self.free {
+            INVALID => {
+                assert!(self.elements.len() < L::CAPACITY, "Link capacity exceeded on nicole::VecListPool");
+                self.elements.push(node);
+                self.elements.len() - 1
+            },
+            x => {
+                self.free = self.elements[x].next();
+                self.elements[x] = node;
+                x
+            }
+        }
+    }
+
+    fn release(&mut self, slot: usize)
+    {
+        self.elements[slot].set_prev(VACANT);
+        self.elements[slot].set_next(self.free);
+        self.free = slot;
+    }
+
+    fn occupied(&self, slot: usize) -> bool
+    {
+        slot < self.elements.len()
+            && !matches!(self.elements[slot].prev(), VACANT | INVALID)
+            && self.elements[slot].next() != INVALID
+    }
+
+    fn is_last(&self, slot: usize) -> bool
+    {
+        slot < self.elements.len() && self.elements[slot].prev() != VACANT && self.elements[slot].next() == INVALID
+    }
+
+    fn check(&self, slot: usize)
+    {
+        assert!(self.occupied(slot), "Invalid index on nicole::VecListPool");
+    }
+
+    // `PoolList` is a plain pair of slots, so a handle can outlive its list. `remove_list` releases both
+    // sentinels, after which the first one is vacant (or reused as some other node), which this catches
+    // before a stale handle walks the free chain.
+    fn check_list(&self, list: PoolList<L>)
+    {
+        assert!(
+            list.first() < self.elements.len() && self.elements[list.first()].prev() == INVALID && self.is_last(list.last()),
+            "Invalid list on nicole::VecListPool"
+        );
+    }
+
+    fn link_before(&mut self, slot: usize, next: usize)
+    {
+        let prev = self.elements[next].prev();
+        self.elements[slot].set_prev(prev);
+        self.elements[slot].set_next(next);
+        self.elements[prev].set_next(slot);
+        self.elements[next].set_prev(slot);
+    }
+
+    fn unlink(&mut self, slot: usize)
+    {
+        let prev = self.elements[slot].prev();
+        let next = self.elements[slot].next();
+        self.elements[prev].set_next(next);
+        self.elements[next].set_prev(prev);
+    }
+
+    pub fn new_list(&mut self) -> PoolList<L>
+    {
+        let first = self.alloc(Node::new_none(INVALID, INVALID));
+        let last = self.alloc(Node::new_none(first, INVALID));
+        self.elements[first].set_next(last);
+
+        PoolList { first: L::from_usize(first), last: L::from_usize(last) }
+    }
+
+    // Drops all elements of the list and gives its nodes back to the pool.
+    pub fn remove_list(&mut self, list: PoolList<L>)
+    {
+        self.check_list(list);
+        let mut i = self.elements[list.first()].next();
+        while i != list.last() {
+            let next = self.elements[i].next();
+            self.elements[i].elem.take();
+            self.release(i);
+            i = next;
+        }
+
+        self.release(list.first());
+        self.release(list.last());
+    }
+
+    pub fn begin(&self, list: PoolList<L>) -> VecListIndex<L>
+    {
+        self.check_list(list);
+        VecListIndex::new(self.elements[list.first()].next())
+    }
+
+    // The last sentinel of the list; inserting before it appends to the list.
+    pub fn past_end(&self, list: PoolList<L>) -> VecListIndex<L>
+    {
+        self.check_list(list);
+        VecListIndex::new(list.last())
+    }
+
+    pub fn rbegin(&self, list: PoolList<L>) -> VecListIndex<L>
+    {
+        self.check_list(list);
+        VecListIndex::new(self.elements[list.last()].prev())
+    }
+
+    // The first sentinel of the list.
+    pub fn rend(&self, list: PoolList<L>) -> VecListIndex<L>
+    {
+        self.check_list(list);
+        VecListIndex::new(list.first())
+    }
+
+    // Like the index traits, `next` and `prev` saturate at the sentinels of the list.
+    pub fn next(&self, index: VecListIndex<L>) -> VecListIndex<L>
+    {
+        match self.elements[index.slot()].next() {
+            INVALID => index,
+            n => VecListIndex::new(n)
+        }
+    }
+
+    pub fn prev(&self, index: VecListIndex<L>) -> VecListIndex<L>
+    {
+        match self.elements[index.slot()].prev() {
+            INVALID => index,
+            n => VecListIndex::new(n)
+        }
+    }
+
+    pub fn is_empty(&self, list: PoolList<L>) -> bool
+    {
+        self.check_list(list);
+        self.elements[list.first()].next() == list.last()
+    }
+
+    pub fn len(&self, list: PoolList<L>) -> usize
+    {
+        self.iter(list).count()
+    }
+
+    pub fn contains(&self, list: PoolList<L>, index: VecListIndex<L>) -> bool
+    {
+        self.check_list(list);
+        let mut i = self.elements[list.first()].next();
+        while i != list.last() {
+            if i == index.slot() {
+                return true;
+            }
+            i = self.elements[i].next();
+        }
+        false
+    }
+
+    pub fn insert(&mut self, next: VecListIndex<L>, element: T)
+    {
+        let next = next.slot();
+        assert!(self.occupied(next) || self.is_last(next), "Invalid index on nicole::VecListPool");
+
+        let pos = self.alloc(Node::new(INVALID, INVALID, element));
+        self.link_before(pos, next);
+    }
+
+    pub fn push_back(&mut self, list: PoolList<L>, element: T)
+    {
+        self.insert(self.past_end(list), element);
+    }
+
+    pub fn push_front(&mut self, list: PoolList<L>, element: T)
+    {
+        self.insert(self.begin(list), element);
+    }
+
+    pub fn remove(&mut self, index: VecListIndex<L>) -> T
+    {
+        let slot = index.slot();
+        self.check(slot);
+
+        self.unlink(slot);
+        let ret = self.elements[slot].elem.take();
+        self.release(slot);
+
+        ret
+    }
+
+    pub fn pop_back(&mut self, list: PoolList<L>) -> T
+    {
+        self.remove(self.rbegin(list))
+    }
+
+    pub fn pop_front(&mut self, list: PoolList<L>) -> T
+    {
+        self.remove(self.begin(list))
+    }
+
+    // Moves all elements of `from` before `at`, in O(1). `from` is left empty. `at` must belong to another
+    // list than `from`; linking `from` into itself would form a cycle. Like the list membership check of
+    // `move_node`, this is O(n), and only checked in debug builds.
+    pub fn splice(&mut self, at: VecListIndex<L>, from: PoolList<L>)
+    {
+        self.check_list(from);
+        debug_assert!(at.slot() != from.last() && !self.contains(from, at), "Index belongs to the spliced list on nicole::VecListPool");
+        let at = at.slot();
+        assert!(self.occupied(at) || self.is_last(at), "Invalid index on nicole::VecListPool");
+
+        if self.is_empty(from) {
+            return;
+        }
+
+        let head = self.elements[from.first()].next();
+        let tail = self.elements[from.last()].prev();
+        let prev = self.elements[at].prev();
+
+        self.elements[prev].set_next(head);
+        self.elements[head].set_prev(prev);
+        self.elements[tail].set_next(at);
+        self.elements[at].set_prev(tail);
+
+        self.elements[from.first()].set_next(from.last());
+        self.elements[from.last()].set_prev(from.first());
+    }
+
+    // Moves `[at, end)` of `list` into a new list, in O(1). `at` must belong to `list`, or be its end; as in
+    // `move_node`, membership is only checked in debug builds.
+    pub fn split_off(&mut self, list: PoolList<L>, at: VecListIndex<L>) -> PoolList<L>
+    {
+        self.check_list(list);
+        debug_assert!(at.slot() == list.last() || self.contains(list, at), "Index does not belong to the list on nicole::VecListPool");
+        let at = at.slot();
+        assert!(self.occupied(at) || at == list.last(), "Invalid index on nicole::VecListPool");
+
+        let ret = self.new_list();
+        if at == list.last() {
+            return ret;
+        }
+
+        let prev = self.elements[at].prev();
+        let tail = self.elements[list.last()].prev();
+
+        self.elements[prev].set_next(list.last());
+        self.elements[list.last()].set_prev(prev);
+
+        self.elements[ret.first()].set_next(at);
+        self.elements[at].set_prev(ret.first());
+        self.elements[tail].set_next(ret.last());
+        self.elements[ret.last()].set_prev(tail);
+
+        ret
+    }
+
+    // Moves the node at `index` from `from` to the back of `to`, in O(1).
+    pub fn move_node(&mut self, index: VecListIndex<L>, from: PoolList<L>, to: PoolList<L>)
+    {
+        let slot = index.slot();
+        self.check(slot);
+        self.check_list(to);
+        debug_assert!(self.contains(from, index), "Index does not belong to the list on nicole::VecListPool");
+
+        self.unlink(slot);
+        self.link_before(slot, to.last());
+    }
+
+    pub fn swap(&mut self, a: VecListIndex<L>, b: VecListIndex<L>)
+    {
+        let (a, b) = (a.slot(), b.slot());
+        self.check(a);
+        self.check(b);
+
+        if a != b {
+            let x = self.elements[a].elem.take();
+            let y = self.elements[b].elem.take();
+            self.elements[a].elem = Slot::new(y);
+            self.elements[b].elem = Slot::new(x);
+        }
+    }
+
+    pub fn iter(&self, list: PoolList<L>) -> PoolIter<'_, T, L>
+    {
+        self.check_list(list);
+        PoolIter { pool: self, index: self.elements[list.first()].next() }
+    }
+}
+
+impl<T, L> Clone for VecListPool<T, L>
+    where
+        T: Clone,
+        L: Link
+{
+    fn clone(&self) -> Self
+    {
+        let elements = self.elements
+            .iter()
+            .enumerate()
+            .map(|(i, node)| Node {
+                prev: node.prev,
+                next: node.next,
+                elem: if self.occupied(i) { Slot::new(node.elem.get().clone()) } else { Slot::empty() }
+            })
+            .collect();
+
+        Self { elements, free: self.free }
+    }
+}
+
+#[cfg(feature = "unsafe")]
+impl<T, L> Drop for VecListPool<T, L>
+    where L: Link
+{
+    fn drop(&mut self)
+    {
+        for i in 0..self.elements.len() {
+            if self.occupied(i) {
+                self.elements[i].elem.take();
+            }
+        }
+    }
+}
+
+impl<T, L> Default for VecListPool<T, L>
+    where L: Link
+{
+    fn default() -> Self { Self::compact() }
+}
+
+impl<T, L> IndexExt<VecListIndex<L>> for VecListPool<T, L>
+    where L: Link
+{
+    fn valid(&self, index: &VecListIndex<L>) -> bool { self.occupied(index.slot()) }
+}
+
+impl<T, L> SwapIndex<VecListIndex<L>> for VecListPool<T, L>
+    where L: Link
+{
+    fn swap_indices(&mut self, a: VecListIndex<L>, b: VecListIndex<L>) { self.swap(a, b) }
+}
+
+impl<T, L> Index<VecListIndex<L>> for VecListPool<T, L>
+    where L: Link
+{
+    type Output = T;
+
+    fn index(&self, index: VecListIndex<L>) -> &Self::Output
+    {
+        self.check(index.slot());
+        self.elements[index.slot()].elem.get()
+    }
+}
+
+impl<T, L> IndexMut<VecListIndex<L>> for VecListPool<T, L>
+    where L: Link
+{
+    fn index_mut(&mut self, index: VecListIndex<L>) -> &mut Self::Output
+    {
+        self.check(index.slot());
+        self.elements[index.slot()].elem.get_mut()
+    }
+}
+
+pub struct PoolIter<'a, T, L = usize>
+    where L: Link
+{
+    pool: &'a VecListPool<T, L>,
+    index: usize
+}
+
+impl<'a, T, L> Iterator for PoolIter<'a, T, L>
+    where L: Link
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        if self.pool.occupied(self.index) {
+            let ret = self.pool.elements[self.index].elem.get();
+            self.index = self.pool.elements[self.index].next();
+            Some(ret)
+        }
+        else {
+            None
+        }
+    }
+}
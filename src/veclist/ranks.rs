@@ -0,0 +1,200 @@
+// Implicit treap over the slots of a VecList, keyed by list position. Every slot gets a tree node
+// (sentinels and free slots stay detached), and parent links allow computing the position of a slot
+// by walking up to the root. All operations are expected O(log n).
+
+const NIL: usize = usize::MAX;
+
+#[derive(Clone)]
+struct RankNode
+{
+    left: usize,
+    right: usize,
+    parent: usize,
+    size: usize,
+    priority: u32
+}
+
+impl RankNode
+{
+    fn new(priority: u32) -> Self { Self { left: NIL, right: NIL, parent: NIL, size: 1, priority } }
+}
+
+#[derive(Clone)]
+pub(super) struct Ranks
+{
+    nodes: Vec<RankNode>,
+    root: usize,
+    seed: u32
+}
+
+impl Ranks
+{
+    pub fn new(slots: &[usize]) -> Self
+    {
+        let mut ret = Self { nodes: Vec::new(), root: NIL, seed: 0x9E37_79B9 };
+        ret.rebuild(slots);
+        ret
+    }
+
+    pub fn len(&self) -> usize { self.size(self.root) }
+
+    pub fn rebuild(&mut self, slots: &[usize])
+    {
+        self.root = NIL;
+        for &slot in slots {
+            self.init(slot);
+            self.root = self.merge(self.root, slot);
+        }
+    }
+
+    pub fn position(&self, slot: usize) -> usize
+    {
+        let mut ret = self.size(self.nodes[slot].left);
+        let mut x = slot;
+
+        while self.nodes[x].parent != NIL {
+            let p = self.nodes[x].parent;
+            if self.nodes[p].right == x {
+                ret += self.size(self.nodes[p].left) + 1;
+            }
+            x = p;
+        }
+
+        ret
+    }
+
+    pub fn nth(&self, mut k: usize) -> Option<usize>
+    {
+        let mut x = self.root;
+
+        while x != NIL {
+            let left = self.nodes[x].left;
+            let left_size = self.size(left);
+
+            if k < left_size {
+                x = left;
+            }
+            else if k == left_size {
+                return Some(x);
+            }
+            else {
+                k -= left_size + 1;
+                x = self.nodes[x].right;
+            }
+        }
+
+        None
+    }
+
+    pub fn insert(&mut self, slot: usize, position: usize)
+    {
+        self.init(slot);
+        let (a, b) = self.split(self.root, position);
+        let a = self.merge(a, slot);
+        self.root = self.merge(a, b);
+        self.nodes[self.root].parent = NIL;
+    }
+
+    pub fn remove(&mut self, slot: usize)
+    {
+        let position = self.position(slot);
+        let (a, b) = self.split(self.root, position);
+        let (_, c) = self.split(b, 1);
+        self.root = self.merge(a, c);
+        if self.root != NIL {
+            self.nodes[self.root].parent = NIL;
+        }
+    }
+
+    fn init(&mut self, slot: usize)
+    {
+        if self.nodes.len() <= slot {
+            self.nodes.resize(slot + 1, RankNode::new(0));
+        }
+
+        // xorshift32
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 17;
+        self.seed ^= self.seed << 5;
+
+        self.nodes[slot] = RankNode::new(self.seed);
+    }
+
+    fn size(&self, x: usize) -> usize
+    {
+        if x == NIL { 0 } else { self.nodes[x].size }
+    }
+
+    fn update(&mut self, x: usize)
+    {
+        self.nodes[x].size = self.size(self.nodes[x].left) + self.size(self.nodes[x].right) + 1;
+    }
+
+    fn set_left(&mut self, x: usize, child: usize)
+    {
+        self.nodes[x].left = child;
+        if child != NIL {
+            self.nodes[child].parent = x;
+        }
+    }
+
+    fn set_right(&mut self, x: usize, child: usize)
+    {
+        self.nodes[x].right = child;
+        if child != NIL {
+            self.nodes[child].parent = x;
+        }
+    }
+
+    // Splits the tree rooted at `x` into the first `k` nodes and the rest.
+    fn split(&mut self, x: usize, k: usize) -> (usize, usize)
+    {
+        if x == NIL {
+            return (NIL, NIL);
+        }
+
+        let left_size = self.size(self.nodes[x].left);
+
+        if k <= left_size {
+            let (a, b) = self.split(self.nodes[x].left, k);
+            self.set_left(x, b);
+            self.update(x);
+            if a != NIL {
+                self.nodes[a].parent = NIL;
+            }
+            (a, x)
+        }
+        else {
+            let (a, b) = self.split(self.nodes[x].right, k - left_size - 1);
+            self.set_right(x, a);
+            self.update(x);
+            if b != NIL {
+                self.nodes[b].parent = NIL;
+            }
+            (x, b)
+        }
+    }
+
+    fn merge(&mut self, a: usize, b: usize) -> usize
+    {
+        if a == NIL {
+            return b;
+        }
+        if b == NIL {
+            return a;
+        }
+
+        if self.nodes[a].priority > self.nodes[b].priority {
+            let right = self.merge(self.nodes[a].right, b);
+            self.set_right(a, right);
+            self.update(a);
+            a
+        }
+        else {
+            let left = self.merge(a, self.nodes[b].left);
+            self.set_left(b, left);
+            self.update(b);
+            b
+        }
+    }
+}
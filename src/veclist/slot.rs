@@ -0,0 +1,31 @@
+// Element storage of a VecList (or VecForwardList) node. Whether a slot is occupied is tracked by the
+// node links (see `VecList::occupied`), so callers must only access occupied slots.
+//
+// The safe build keeps an `Option<T>` that double checks every access. With the `unsafe` feature,
+// the element is stored as `MaybeUninit<T>`, avoiding the discriminant.
+
+#[cfg(not(feature = "unsafe"))]
+pub(crate) struct Slot<T>(Option<T>);
+
+#[cfg(not(feature = "unsafe"))]
+impl<T> Slot<T>
+{
+    pub fn new(elem: T) -> Self { Slot(Some(elem)) }
+    pub fn empty() -> Self { Slot(None) }
+    pub fn get(&self) -> &T { self.0.as_ref().expect(super::ERROR_MSG) }
+    pub fn get_mut(&mut self) -> &mut T { self.0.as_mut().expect(super::ERROR_MSG) }
+    pub fn take(&mut self) -> T { self.0.take().expect(super::ERROR_MSG) }
+}
+
+#[cfg(feature = "unsafe")]
+pub(crate) struct Slot<T>(std::mem::MaybeUninit<T>);
+
+#[cfg(feature = "unsafe")]
+impl<T> Slot<T>
+{
+    pub fn new(elem: T) -> Self { Slot(std::mem::MaybeUninit::new(elem)) }
+    pub fn empty() -> Self { Slot(std::mem::MaybeUninit::uninit()) }
+    pub fn get(&self) -> &T { unsafe { self.0.assume_init_ref() } }
+    pub fn get_mut(&mut self) -> &mut T { unsafe { self.0.assume_init_mut() } }
+    pub fn take(&mut self) -> T { unsafe { self.0.assume_init_read() } }
+}
@@ -0,0 +1,216 @@
+use std::{collections::VecDeque, ops::Index};
+
+use nicole::{VecList, ForwardIndex, BidirectionalIndex, RandomAccessIndex, SwapIndex, algorithm};
+#[cfg(feature = "unsafe")]
+use nicole::LinkedList;
+
+mod common;
+
+use common::Rng;
+
+// Every index of the collection in order, followed by `past_end()`, so that positions `0..=len` map to
+// indices and back.
+fn indices<C, I>(collection: &C) -> Vec<I>
+    where C: BidirectionalIndex<I> + ?Sized, I: Copy + PartialEq
+{
+    let mut ret = vec![collection.begin()];
+    while *ret.last().unwrap() != collection.past_end() {
+        ret.push(collection.next(*ret.last().unwrap()));
+    }
+    ret
+}
+
+fn contents<C, I>(collection: &C) -> Vec<u8>
+    where C: BidirectionalIndex<I> + Index<I, Output = u8>, I: Copy + PartialEq
+{
+    let indices = indices(collection);
+    indices[..indices.len() - 1].iter().map(|&i| collection[i]).collect()
+}
+
+fn position<I>(indices: &[I], index: I) -> usize
+    where I: PartialEq
+{
+    indices.iter().position(|x| *x == index).expect("index outside of the collection")
+}
+
+// A random case: values with many duplicates, and a subrange `lo..hi` of them, which is empty in some
+// rounds and covers the whole collection (possibly empty itself) in others.
+fn case(rng: &mut Rng, round: usize) -> (Vec<u8>, usize, usize)
+{
+    let len = round % 12;
+    let values: Vec<u8> = (0..len).map(|_| rng.below(4) as u8).collect();
+    let (lo, hi) = match round % 5 {
+        0 => { let at = rng.below(len + 1); (at, at) },
+        1 => (0, len),
+        _ => {
+            let lo = rng.below(len + 1);
+            (lo, lo + rng.below(len - lo + 1))
+        }
+    };
+    (values, lo, hi)
+}
+
+fn or_past(found: Option<usize>, lo: usize, hi: usize) -> usize
+{
+    found.map_or(hi, |p| lo + p)
+}
+
+fn check_queries<C, I, M>(make: M)
+    where
+        C: BidirectionalIndex<I> + Index<I, Output = u8>,
+        I: Copy + PartialEq,
+        M: Fn(&[u8]) -> C
+{
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for round in 0..500 {
+        let (values, lo, hi) = case(&mut rng, round);
+        let collection = make(&values);
+        let idx = indices(&collection);
+        let (first, last) = (idx[lo], idx[hi]);
+        let range = &values[lo..hi];
+        let v = rng.below(4) as u8;
+
+        let found = algorithm::find_if(&collection, first, last, |x| *x == v);
+        assert_eq!(position(&idx, found), or_past(range.iter().position(|x| *x == v), lo, hi));
+
+        assert_eq!(algorithm::count(&collection, first, last, &v), range.iter().filter(|x| **x == v).count());
+        assert_eq!(algorithm::count_if(&collection, first, last, |x| *x < v), range.iter().filter(|x| **x < v).count());
+        assert_eq!(algorithm::distance(&collection, first, last), hi - lo);
+        assert_eq!(algorithm::is_sorted(&collection, first, last), range.windows(2).all(|w| w[0] <= w[1]));
+
+        let adjacent = algorithm::adjacent_find(&collection, first, last);
+        assert_eq!(position(&idx, adjacent), or_past(range.windows(2).position(|w| w[0] == w[1]), lo, hi));
+
+        // An empty needle matches at `first`, as `str::find("")` does.
+        let needle: Vec<u8> = (0..rng.below(3)).map(|_| rng.below(4) as u8).collect();
+        let found = algorithm::search(&collection, first, last, &needle, 0, needle.len());
+        let expected = if needle.is_empty() { Some(0) } else { range.windows(needle.len()).position(|w| w == &needle[..]) };
+        assert_eq!(position(&idx, found), or_past(expected, lo, hi));
+
+        let mut sorted = values.clone();
+        sorted.sort();
+        let collection = make(&sorted);
+        let idx = indices(&collection);
+        let bound = algorithm::lower_bound(&collection, idx[lo], idx[hi], &v);
+        assert_eq!(position(&idx, bound), lo + sorted[lo..hi].partition_point(|x| *x < v));
+    }
+}
+
+fn check_mutations<C, I, M>(make: M)
+    where
+        C: BidirectionalIndex<I> + SwapIndex<I> + Index<I, Output = u8>,
+        I: Copy + PartialEq,
+        M: Fn(&[u8]) -> C
+{
+    let mut rng = Rng(0x6a09_e667_f3bc_c908);
+    for round in 0..500 {
+        let (values, lo, hi) = case(&mut rng, round);
+
+        let mut collection = make(&values);
+        let idx = indices(&collection);
+        algorithm::reverse(&mut collection, idx[lo], idx[hi]);
+        let mut expected = values.clone();
+        expected[lo..hi].reverse();
+        assert_eq!(contents(&collection), expected);
+
+        // Also rotate by nothing (`middle == first`) and by everything (`middle == last`).
+        let middle = match round % 4 {
+            0 => lo,
+            1 => hi,
+            _ => lo + rng.below(hi - lo + 1)
+        };
+        let mut collection = make(&values);
+        let idx = indices(&collection);
+        let ret = algorithm::rotate(&mut collection, idx[lo], idx[middle], idx[hi]);
+        let mut expected = values.clone();
+        expected[lo..hi].rotate_left(middle - lo);
+        assert_eq!(contents(&collection), expected);
+        assert_eq!(position(&idx, ret), lo + (hi - middle), "rotate {}..{} at {}", lo, hi, middle);
+
+        let v = rng.below(4) as u8;
+        let mut collection = make(&values);
+        let idx = indices(&collection);
+        let split = position(&idx, algorithm::partition(&mut collection, idx[lo], idx[hi], |x| *x < v));
+        let result = contents(&collection);
+        assert_eq!(result[..lo], values[..lo]);
+        assert_eq!(result[hi..], values[hi..]);
+        assert_eq!(split - lo, values[lo..hi].iter().filter(|x| **x < v).count());
+        assert!(result[lo..split].iter().all(|x| *x < v));
+        assert!(result[split..hi].iter().all(|x| *x >= v));
+
+        let mut a = result[lo..hi].to_vec();
+        let mut b = values[lo..hi].to_vec();
+        a.sort();
+        b.sort();
+        assert_eq!(a, b);
+    }
+}
+
+fn check_binary_search<C>(collection: &C, sorted: &[u8], lo: usize, hi: usize)
+    where C: RandomAccessIndex<usize> + Index<usize, Output = u8> + ?Sized
+{
+    for v in 0..5 {
+        // With duplicates, any match will do; where to insert is unique.
+        match (algorithm::binary_search(collection, lo, hi, &v), sorted[lo..hi].binary_search(&v)) {
+            (Ok(i), Ok(_)) => assert!(lo <= i && i < hi && sorted[i] == v),
+            (Err(i), Err(j)) => assert_eq!(i, lo + j),
+            (ours, std) => panic!("{:?} vs {:?} for {} in {:?}", ours, std, v, &sorted[lo..hi])
+        }
+    }
+}
+
+#[test]
+fn queries_on_vec() { check_queries(|v| v.to_vec()) }
+
+#[test]
+fn queries_on_veclist() { check_queries(|v| v.iter().copied().collect::<VecList<u8>>()) }
+
+#[test]
+#[cfg(feature = "unsafe")]
+fn queries_on_linkedlist() { check_queries(|v| v.iter().copied().collect::<LinkedList<u8>>()) }
+
+#[test]
+fn mutations_on_vec() { check_mutations(|v| v.to_vec()) }
+
+#[test]
+fn mutations_on_veclist() { check_mutations(|v| v.iter().copied().collect::<VecList<u8>>()) }
+
+#[test]
+#[cfg(feature = "unsafe")]
+fn mutations_on_linkedlist() { check_mutations(|v| v.iter().copied().collect::<LinkedList<u8>>()) }
+
+#[test]
+fn binary_search_matches_slices()
+{
+    let mut rng = Rng(0xbb67_ae85_84ca_a73b);
+    for round in 0..500 {
+        let (mut values, lo, hi) = case(&mut rng, round);
+        values.sort();
+
+        check_binary_search(&values, &values, lo, hi);
+        check_binary_search(&values.iter().copied().collect::<VecDeque<u8>>(), &values, lo, hi);
+        check_binary_search(&values[..], &values, lo, hi);
+    }
+}
+
+#[test]
+fn empty_collections()
+{
+    let list: VecList<u8> = VecList::new();
+    let (first, last) = (list.begin(), list.past_end());
+    assert_eq!(first, last);
+
+    assert_eq!(algorithm::find_if(&list, first, last, |_| true), last);
+    assert_eq!(algorithm::count(&list, first, last, &0), 0);
+    assert_eq!(algorithm::distance(&list, first, last), 0);
+    assert_eq!(algorithm::lower_bound(&list, first, last, &0), last);
+    assert!(algorithm::is_sorted(&list, first, last));
+    assert_eq!(algorithm::adjacent_find(&list, first, last), last);
+    assert_eq!(algorithm::search(&list, first, last, &vec![0u8], 0, 1), last);
+
+    let mut vec: Vec<u8> = Vec::new();
+    assert_eq!(algorithm::binary_search(&vec, 0, 0, &0), Err(0));
+    algorithm::reverse(&mut vec, 0, 0);
+    assert_eq!(algorithm::rotate(&mut vec, 0, 0, 0), 0);
+    assert_eq!(algorithm::partition(&mut vec, 0, 0, |_| true), 0);
+}
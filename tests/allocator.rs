@@ -0,0 +1,52 @@
+use std::num::NonZeroU32;
+
+use nicole::{IdAllocator, idlike::allocator::ReleaseError};
+
+#[test]
+fn allocate_with_generation_reports_the_current_generation()
+{
+    let mut ids: IdAllocator<u32> = IdAllocator::with_generations();
+    let (a, first) = ids.allocate_with_generation();
+    assert_eq!((a, first), (0, 0));
+    assert!(ids.is_current(a, first));
+
+    ids.release(a).unwrap();
+    let (b, second) = ids.allocate_with_generation();
+    assert_eq!((b, second), (0, 1));
+    assert!(!ids.is_current(a, first));
+    assert_eq!(ids.release_generation(a, first), Err(ReleaseError::StaleGeneration));
+    assert_eq!(ids.release_generation(b, second), Ok(()));
+}
+
+#[test]
+fn allocate_with_generation_without_generations()
+{
+    let mut ids: IdAllocator<u32> = IdAllocator::new();
+    let (a, generation) = ids.allocate_with_generation();
+    assert_eq!(generation, 0);
+    ids.release(a).unwrap();
+
+    let (b, generation) = ids.allocate_with_generation();
+    assert_eq!((b, generation), (a, 0));
+    assert!(ids.is_current(b, generation));
+}
+
+#[test]
+fn default_needs_no_default_key()
+{
+    let mut ids: IdAllocator<NonZeroU32> = IdAllocator::default();
+    assert_eq!(ids.allocate(), NonZeroU32::new(1).unwrap());
+}
+
+#[test]
+fn never_hands_out_null()
+{
+    let mut ids: IdAllocator<u8> = IdAllocator::new();
+    for i in 0..255 {
+        assert_eq!(ids.allocate(), i);
+    }
+    assert_eq!(ids.high_water_mark(), 255);
+
+    ids.clear();
+    assert_eq!(ids.allocate(), 0);
+}
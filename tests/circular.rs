@@ -0,0 +1,111 @@
+use nicole::{CircularVecList, ForwardIndex, BackwardIndex, BidirectionalIndex};
+
+// Elements are their own positions, so that where an index landed can be read off the element.
+fn circle(len: usize, ranked: bool) -> CircularVecList<usize>
+{
+    let mut list: CircularVecList<usize> = (0..len).collect();
+    if ranked {
+        list.enable_ranks();
+    }
+    list
+}
+
+#[test]
+fn increment_and_decrement_wrap_around()
+{
+    let list = circle(5, false);
+
+    assert_eq!(list.next(list.rbegin()), list.begin());
+    assert_eq!(list.prev(list.begin()), list.rbegin());
+
+    let mut idx = list.begin();
+    for step in 1..=12 {
+        list.increment(&mut idx);
+        assert_eq!(list[idx], step % 5);
+    }
+    for step in (0..12).rev() {
+        list.decrement(&mut idx);
+        assert_eq!(list[idx], step % 5);
+    }
+}
+
+#[test]
+fn sentinels_enter_the_circle_and_saturate()
+{
+    let list = circle(3, false);
+
+    assert_eq!(list.next(list.rend()), list.begin());
+    assert_eq!(list.prev(list.past_end()), list.rbegin());
+    assert_eq!(list.next(list.past_end()), list.past_end());
+    assert_eq!(list.prev(list.rend()), list.rend());
+
+    // Entering from a sentinel takes the first step, and the rest go around.
+    let mut idx = list.rend();
+    list.move_forward(&mut idx, 5);
+    assert_eq!(list[idx], 1);
+
+    let mut idx = list.past_end();
+    list.move_backward(&mut idx, 5);
+    assert_eq!(list[idx], 1);
+}
+
+#[test]
+fn advance_goes_around_by_distance_modulo_len()
+{
+    for ranked in [false, true] {
+        let len = 7;
+        let list = circle(len, ranked);
+
+        for start in 0..len {
+            for distance in -20isize..=20 {
+                let mut idx = list.begin();
+                list.move_forward(&mut idx, start);
+                list.advance(&mut idx, distance);
+
+                let expected = (start as isize + distance).rem_euclid(len as isize) as usize;
+                assert_eq!(list[idx], expected, "start {}, distance {}, ranked {}", start, distance, ranked);
+            }
+        }
+    }
+}
+
+#[test]
+fn moving_on_an_empty_list_stays_put()
+{
+    let list = circle(0, false);
+
+    let mut idx = list.begin();
+    list.move_forward(&mut idx, 3);
+    assert_eq!(idx, list.past_end());
+    list.increment(&mut idx);
+    assert_eq!(idx, list.past_end());
+    assert_eq!(list.iter_from(list.begin()).count(), 0);
+}
+
+#[test]
+fn iter_from_goes_around_once()
+{
+    let list = circle(5, false);
+
+    let mut start = list.begin();
+    list.move_forward(&mut start, 3);
+    assert_eq!(list.iter_from(start).copied().collect::<Vec<_>>(), [3, 4, 0, 1, 2]);
+    assert_eq!(list.iter_from(list.rbegin()).copied().collect::<Vec<_>>(), [4, 0, 1, 2, 3]);
+
+    // The sentinels are no element to start from.
+    assert_eq!(list.iter_from(list.past_end()).count(), 0);
+    assert_eq!(list.iter_from(list.rend()).count(), 0);
+}
+
+#[test]
+fn removal_keeps_the_circle_closed()
+{
+    let mut list = circle(4, false);
+    let last = list.rbegin();
+    list.remove(last);
+    let first = list.begin();
+    list.remove(first);
+
+    assert_eq!(list.next(list.rbegin()), list.begin());
+    assert_eq!(list.iter_from(list.rbegin()).copied().collect::<Vec<_>>(), [2, 1]);
+}
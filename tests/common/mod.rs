@@ -0,0 +1,32 @@
+// Helpers shared by the integration tests; not every test crate uses all of them.
+#![allow(dead_code)]
+
+use std::{collections::BTreeSet, fmt::Debug};
+
+// Deterministic xorshift, so that failures can be replayed.
+pub struct Rng(pub u64);
+
+impl Rng
+{
+    pub fn next(&mut self) -> u64
+    {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    pub fn below(&mut self, n: usize) -> usize
+    {
+        (self.next() % n as u64) as usize
+    }
+}
+
+// Checks an ordered set against its model: the same elements in the same order, and the same size.
+pub fn check_ordered<T>(elements: impl IntoIterator<Item = T>, len: usize, is_empty: bool, model: &BTreeSet<T>)
+    where T: Ord + Copy + Debug
+{
+    assert_eq!(elements.into_iter().collect::<Vec<_>>(), model.iter().copied().collect::<Vec<_>>());
+    assert_eq!(len, model.len());
+    assert_eq!(is_empty, model.is_empty());
+}
@@ -0,0 +1,28 @@
+use std::num::NonZeroU32;
+
+use nicole::IdMap;
+
+#[test]
+fn id_map_holds_the_highest_key()
+{
+    let mut map: IdMap<u8, &str> = IdMap::new();
+    assert_eq!(map.insert(254, "last"), None);
+    assert_eq!(map.max_key(), 254);
+    assert_eq!(map.iter().collect::<Vec<_>>(), [(254, &"last")]);
+}
+
+#[test]
+#[should_panic(expected = "Null key on nicole::IdMap")]
+fn id_map_rejects_null()
+{
+    let mut map: IdMap<u8, ()> = IdMap::new();
+    map.insert(255, ());
+}
+
+#[test]
+#[should_panic(expected = "Null key on nicole::IdMap")]
+fn id_map_rejects_none_keys()
+{
+    let mut map: IdMap<Option<NonZeroU32>, ()> = IdMap::new();
+    map.insert(None, ());
+}
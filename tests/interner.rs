@@ -0,0 +1,63 @@
+use std::num::NonZeroU32;
+
+use nicole::{Interner, FrozenInterner, IdLike, typedvec::TypedVec};
+
+#[test]
+fn ids_follow_interning_order()
+{
+    let mut interner: Interner<String, u32> = Interner::new();
+    assert_eq!(interner.intern("a"), 0);
+    assert_eq!(interner.intern("b"), 1);
+    assert_eq!(interner.intern("a"), 0);
+    assert_eq!(interner.len(), 2);
+    assert_eq!(interner[1], "b");
+    assert_eq!(interner.get("c"), None);
+}
+
+#[test]
+#[should_panic(expected = "Out of keys")]
+fn running_out_of_ids_panics()
+{
+    let mut interner: Interner<u32, u8> = Interner::new();
+    for i in 0..254 {
+        assert!(!interner.intern(&i).is_null());
+    }
+    interner.intern(&254);
+}
+
+#[cfg(feature = "derive")]
+mod derived
+{
+    use nicole::{Interner, IdLike};
+
+    #[derive(IdLike)]
+    #[id_like(null = 0)]
+    struct Symbol(u32);
+
+    #[test]
+    fn null_at_index_zero_is_skipped()
+    {
+        let mut interner: Interner<String, Symbol> = Interner::new();
+        let a = interner.intern("a");
+        let b = interner.intern("b");
+
+        assert!(!a.is_null());
+        assert_eq!((a, b), (Symbol(1), Symbol(2)));
+        assert_eq!(interner[a], "a");
+        assert_eq!(interner.resolve(Symbol::null()), None);
+        assert_eq!(interner.iter().map(|(id, value)| (id, value.as_str())).collect::<Vec<_>>(), [(a, "a"), (b, "b")]);
+    }
+}
+
+#[test]
+fn default_needs_no_default_ids()
+{
+    let mut interner: Interner<String, NonZeroU32> = Interner::default();
+    assert_eq!(interner.intern("a").to_index(), 0);
+
+    let frozen: FrozenInterner<String, NonZeroU32> = FrozenInterner::default();
+    assert!(frozen.is_empty());
+
+    let vec: TypedVec<NonZeroU32, i32> = TypedVec::default();
+    assert!(vec.is_empty());
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "unsafe")]
+
+use nicole::{LinkedList, ForwardIndex, linkedlist::LinkedListIndex};
+
+fn indices<T>(list: &LinkedList<T>) -> Vec<LinkedListIndex<T>>
+{
+    let mut ret = Vec::new();
+    let mut index = list.begin();
+    while index != list.past_end() {
+        ret.push(index);
+        list.increment(&mut index);
+    }
+    ret
+}
+
+#[test]
+fn sort_is_stable_and_keeps_indices()
+{
+    // (key, insertion order); many equal keys, so that stability shows.
+    let values: Vec<(u32, usize)> = (0..200).map(|i| ((i * 7919 % 13) as u32, i)).collect();
+    let mut list: LinkedList<(u32, usize)> = values.iter().copied().collect();
+    let before = indices(&list);
+
+    list.sort_by_key(|&(key, _)| key);
+
+    let mut expected = values.clone();
+    expected.sort_by_key(|&(key, _)| key);
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+
+    // Every index still refers to the element it referred to before.
+    for (index, value) in before.iter().zip(&values) {
+        assert_eq!(list[*index], *value);
+    }
+    assert_eq!(indices(&list).len(), values.len());
+}
+
+#[test]
+fn sort_and_sort_by_agree()
+{
+    let values = [5, 3, 9, 1, 3, 7, 0];
+    let mut a: LinkedList<i32> = values.iter().copied().collect();
+    let mut b = a.clone();
+    a.sort();
+    b.sort_by(|x, y| y.cmp(x));
+    b.reverse();
+    assert_eq!(a.iter().copied().collect::<Vec<_>>(), [0, 1, 3, 3, 5, 7, 9]);
+    assert_eq!(b.iter().copied().collect::<Vec<_>>(), [0, 1, 3, 3, 5, 7, 9]);
+}
+
+#[test]
+fn reverse_keeps_indices()
+{
+    let mut list: LinkedList<usize> = (0..10).collect();
+    let before = indices(&list);
+
+    list.reverse();
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), (0..10).rev().collect::<Vec<_>>());
+    for (i, index) in before.iter().enumerate() {
+        assert_eq!(list[*index], i);
+    }
+
+    let mut empty: LinkedList<usize> = LinkedList::new();
+    empty.reverse();
+    empty.sort();
+    assert!(empty.is_empty());
+}
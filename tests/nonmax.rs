@@ -0,0 +1,58 @@
+use std::mem::size_of;
+
+use nicole::{IdLike, NonMaxU8, NonMaxU16, NonMaxU32, NonMaxU64, NonMaxUsize};
+
+#[test]
+fn option_takes_no_space()
+{
+    assert_eq!(size_of::<Option<NonMaxU8>>(), 1);
+    assert_eq!(size_of::<Option<NonMaxU16>>(), 2);
+    assert_eq!(size_of::<Option<NonMaxU32>>(), 4);
+    assert_eq!(size_of::<Option<NonMaxU64>>(), 8);
+    assert_eq!(size_of::<Option<NonMaxUsize>>(), size_of::<usize>());
+}
+
+#[test]
+fn values_round_trip()
+{
+    for value in 0..u8::MAX {
+        assert_eq!(NonMaxU8::new(value).unwrap().get(), value);
+    }
+    assert_eq!(NonMaxU8::new(u8::MAX), None);
+
+    for value in [0, 1, 12345, u32::MAX / 2, u32::MAX - 1] {
+        let x = NonMaxU32::new(value).unwrap();
+        assert_eq!(x.get(), value);
+        assert_eq!(u32::from(x), value);
+        assert_eq!(format!("{} {:?}", x, x), format!("{} {:?}", value, value));
+    }
+    assert_eq!(NonMaxU32::new(u32::MAX), None);
+    assert_eq!(NonMaxU64::new(u64::MAX - 1).map(NonMaxU64::get), Some(u64::MAX - 1));
+    assert_eq!(NonMaxUsize::new(usize::MAX), None);
+}
+
+// Stored inverted, but ordered as the integers.
+#[test]
+fn order_follows_the_values()
+{
+    let values: Vec<NonMaxU16> = [0, 1, 2, 300, u16::MAX - 1].iter().map(|&x| NonMaxU16::new(x).unwrap()).collect();
+    assert!(values.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn ids_round_trip()
+{
+    for index in 0..254 {
+        let id = NonMaxU8::from_index(index);
+        assert!(!id.is_null());
+        assert_eq!(id.to_index(), index);
+    }
+
+    for index in [0, 1, 1000, u32::MAX as usize - 1] {
+        let id = <Option<NonMaxU32>>::from_index(index);
+        assert_eq!(id, NonMaxU32::new(index as u32));
+        assert_eq!(id.to_index(), index);
+    }
+    assert_eq!(<Option<NonMaxU32>>::try_from_index(u32::MAX as usize), None);
+    assert!(<Option<NonMaxU32>>::null().is_none());
+}
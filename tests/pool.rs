@@ -0,0 +1,122 @@
+use std::{panic::{self, AssertUnwindSafe}, rc::Rc};
+
+use nicole::VecListPool;
+
+#[test]
+fn splice_and_split_off_move_whole_ranges()
+{
+    let mut pool = VecListPool::new();
+    let a = pool.new_list();
+    let b = pool.new_list();
+    for i in 0..4 {
+        pool.push_back(a, i);
+        pool.push_back(b, 10 + i);
+    }
+
+    let at = pool.next(pool.begin(a));
+    pool.splice(at, b);
+    assert_eq!(pool.iter(a).copied().collect::<Vec<_>>(), [0, 10, 11, 12, 13, 1, 2, 3]);
+    assert!(pool.is_empty(b));
+
+    let c = pool.split_off(a, at);
+    assert_eq!(pool.iter(a).copied().collect::<Vec<_>>(), [0, 10, 11, 12, 13]);
+    assert_eq!(pool.iter(c).copied().collect::<Vec<_>>(), [1, 2, 3]);
+
+    let d = pool.split_off(c, pool.past_end(c));
+    assert!(pool.is_empty(d));
+    assert_eq!(pool.len(c), 3);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "Index belongs to the spliced list")]
+fn splice_into_itself_panics()
+{
+    let mut pool = VecListPool::new();
+    let a = pool.new_list();
+    pool.push_back(a, 0);
+    pool.push_back(a, 1);
+
+    let at = pool.rbegin(a);
+    pool.splice(at, a);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "Index belongs to the spliced list")]
+fn splice_before_own_end_panics()
+{
+    let mut pool = VecListPool::new();
+    let a = pool.new_list();
+    pool.push_back(a, 0);
+
+    let at = pool.past_end(a);
+    pool.splice(at, a);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "Index does not belong to the list")]
+fn split_off_at_foreign_index_panics()
+{
+    let mut pool = VecListPool::new();
+    let a = pool.new_list();
+    let b = pool.new_list();
+    pool.push_back(a, 0);
+    pool.push_back(b, 1);
+
+    let at = pool.begin(b);
+    pool.split_off(a, at);
+}
+
+#[test]
+fn remove_list_drops_each_element_once()
+{
+    let counter = Rc::new(());
+    let mut pool = VecListPool::new();
+    let a = pool.new_list();
+    for _ in 0..3 {
+        pool.push_back(a, counter.clone());
+    }
+
+    pool.remove_list(a);
+    assert_eq!(Rc::strong_count(&counter), 1);
+
+    // Reusing the released nodes must not bring the old handle back to life.
+    let b = pool.new_list();
+    pool.push_back(b, counter.clone());
+    let result = panic::catch_unwind(AssertUnwindSafe(|| pool.remove_list(a)));
+    assert!(result.is_err());
+    assert_eq!(pool.len(b), 1);
+    assert_eq!(Rc::strong_count(&counter), 2);
+}
+
+#[test]
+#[should_panic(expected = "Invalid list on nicole::VecListPool")]
+fn remove_list_twice_panics()
+{
+    let mut pool = VecListPool::new();
+    let a = pool.new_list();
+    pool.push_back(a, 0);
+    pool.remove_list(a);
+    pool.remove_list(a);
+}
+
+#[test]
+fn removed_lists_are_rejected()
+{
+    let mut pool = VecListPool::new();
+    let a = pool.new_list();
+    let b = pool.new_list();
+    pool.push_back(b, 1);
+    pool.remove_list(a);
+
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| pool.len(a))).is_err());
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| pool.is_empty(a))).is_err());
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| pool.iter(a).count())).is_err());
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| pool.split_off(a, pool.begin(b)))).is_err());
+
+    let at = pool.begin(b);
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| pool.splice(at, a))).is_err());
+    assert_eq!(pool.iter(b).copied().collect::<Vec<_>>(), [1]);
+}
@@ -0,0 +1,89 @@
+use std::{cmp::Ordering, collections::VecDeque};
+
+use nicole::{ForwardIndex, BackwardIndex, RandomAccessIndex};
+
+// Every position in order, sentinels included: `rend(), begin(), ..., rbegin(), past_end()`.
+fn positions<C>(collection: &C) -> Vec<usize>
+    where C: RandomAccessIndex<usize> + ?Sized
+{
+    let mut ret = vec![collection.rend()];
+    while *ret.last().unwrap() != collection.past_end() {
+        ret.push(collection.next(*ret.last().unwrap()));
+    }
+    ret
+}
+
+fn check<C>(collection: &C)
+    where C: RandomAccessIndex<usize> + ?Sized
+{
+    let positions = positions(collection);
+    let last = positions.len() as isize - 1;
+
+    for (a, &from) in positions.iter().enumerate() {
+        for distance in -(last + 3)..=(last + 3) {
+            // Jumping lands where stepping one at a time does, clamped to the sentinels.
+            let target = (a as isize + distance).clamp(0, last);
+            let mut jumped = from;
+            collection.jump(&mut jumped, distance);
+            assert_eq!(jumped, positions[target as usize], "jump from {} by {}", from, distance);
+
+            let mut stepped = from;
+            collection.advance(&mut stepped, distance);
+            assert_eq!(jumped, stepped);
+        }
+
+        // The extremes saturate instead of overflowing.
+        for distance in [isize::MAX, isize::MIN, isize::MIN + 1] {
+            let mut jumped = from;
+            collection.jump(&mut jumped, distance);
+            let expected = if distance > 0 { collection.past_end() } else { collection.rend() };
+            assert_eq!(jumped, expected);
+        }
+
+        for (b, &to) in positions.iter().enumerate() {
+            assert_eq!(collection.distance(&from, &to), b as isize - a as isize);
+            assert_eq!(collection.cmp_indices(&from, &to), a.cmp(&b));
+        }
+    }
+
+    assert_eq!(collection.distance(&collection.rend(), &collection.past_end()), last);
+    assert_eq!(collection.cmp_indices(&collection.rend(), &collection.begin()), Ordering::Less);
+}
+
+#[test]
+fn vec_jumps_saturate()
+{
+    for len in 0..5 {
+        check(&(0..len).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn vec_deque_jumps_saturate()
+{
+    for len in 0..5 {
+        check(&(0..len).collect::<VecDeque<_>>());
+    }
+}
+
+#[test]
+fn slice_jumps_saturate()
+{
+    let vec: Vec<i32> = (0..4).collect();
+    for len in 0..5 {
+        check(&vec[..len]);
+    }
+}
+
+#[test]
+fn indices_past_the_end_count_as_past_end()
+{
+    let vec = vec![1, 2, 3];
+    let mut i = 10;
+    vec.jump(&mut i, -1);
+    assert_eq!(i, vec.rbegin());
+
+    let mut i = 10;
+    vec.jump(&mut i, 1);
+    assert_eq!(i, vec.past_end());
+}
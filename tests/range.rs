@@ -0,0 +1,50 @@
+use nicole::{VecList, ForwardIndex, BackwardIndex, IndexRange, IndexIterExt};
+
+#[test]
+fn index_range_construction()
+{
+    let vec = vec![1, 2, 3];
+
+    assert_eq!(IndexRange::all(&vec), IndexRange::new(0, 3));
+    assert_eq!(IndexRange::from(1..2), IndexRange { begin: 1, end: 2 });
+    assert!(IndexRange::new(2, 2).is_empty());
+    assert!(!IndexRange::all(&vec).is_empty());
+    assert!(IndexRange::all(&Vec::<i32>::new()).is_empty());
+}
+
+#[test]
+fn index_iter_yields_the_range()
+{
+    let vec = vec![10, 20, 30, 40];
+
+    assert_eq!(vec.index_iter(1..3).collect::<Vec<_>>(), [1, 2]);
+    assert_eq!(vec.index_iter(IndexRange::all(&vec)).collect::<Vec<_>>(), [0, 1, 2, 3]);
+    assert_eq!(vec.index_iter(2..2).count(), 0);
+    assert_eq!(vec.indexed_iter(2..4).collect::<Vec<_>>(), [(2, &30), (3, &40)]);
+
+    let list: VecList<char> = "abcd".chars().collect();
+    let second = list.next(list.begin());
+    let pairs: Vec<_> = list.indexed_iter(IndexRange::new(second, list.past_end())).collect();
+    assert_eq!(pairs.iter().map(|&(_, c)| *c).collect::<String>(), "bcd");
+    assert_eq!(pairs[0].0, second);
+    assert_eq!(pairs[2].0, list.rbegin());
+}
+
+#[test]
+fn iteration_stops_at_an_invalid_index()
+{
+    // An `end` past the collection would never be reached; `past_end()` is invalid, so iteration ends there.
+    let vec = vec![1, 2, 3];
+    assert_eq!(vec.index_iter(1..10).collect::<Vec<_>>(), [1, 2]);
+    assert_eq!(vec.index_iter(5..10).count(), 0);
+
+    // Same with an `end` that has been removed from the list, and is no longer reachable.
+    let mut list: VecList<i32> = (0..5).collect();
+    let mut end = list.begin();
+    list.move_forward(&mut end, 2);
+    list.remove(end);
+    assert_eq!(list.indexed_iter(IndexRange::new(list.begin(), end)).map(|(_, x)| *x).collect::<Vec<_>>(), [0, 1, 3, 4]);
+
+    // And with a `begin` that is no element at all.
+    assert_eq!(list.index_iter(IndexRange::new(list.rend(), list.past_end())).count(), 0);
+}
@@ -0,0 +1,99 @@
+use nicole::{SafeVec, ForwardIndex, BackwardIndex, SwapIndex, safevec::IndexError};
+
+#[test]
+fn insert_accepts_every_position_up_to_end()
+{
+    let mut sv: SafeVec<i32> = SafeVec::new();
+    sv.insert(sv.past_end(), 1);
+    sv.insert(sv.past_end(), 3);
+    sv.insert(sv.begin(), 0);
+    let mut at = sv.begin();
+    sv.move_forward(&mut at, 2);
+    sv.insert(at, 2);
+    assert_eq!(*sv, [0, 1, 2, 3]);
+}
+
+#[test]
+fn end_saturates_and_stays_an_insert_position()
+{
+    let mut sv: SafeVec<i32> = (0..3).collect();
+    let mut at = sv.rbegin();
+    sv.move_forward(&mut at, 5);
+    assert_eq!(at, sv.past_end());
+
+    sv.insert(at, 3);
+    assert_eq!(*sv, [0, 1, 2, 3]);
+}
+
+#[test]
+#[should_panic(expected = "Index from another collection on nicole::SafeVec")]
+fn moving_a_foreign_index_panics()
+{
+    let a: SafeVec<i32> = (0..3).collect();
+    let b: SafeVec<i32> = (0..3).collect();
+    let mut index = a.begin();
+    b.increment(&mut index);
+}
+
+#[test]
+#[should_panic(expected = "Index from another collection on nicole::SafeVec")]
+fn moving_a_foreign_index_backward_panics()
+{
+    let a: SafeVec<i32> = (0..3).collect();
+    let b: SafeVec<i32> = (0..3).collect();
+    let mut index = a.past_end();
+    b.move_backward(&mut index, 2);
+}
+
+#[test]
+fn index_errors_display_and_convert()
+{
+    let mut sv: SafeVec<i32> = (0..3).collect();
+    let other: SafeVec<i32> = SafeVec::new();
+    let end = sv.past_end();
+    let first = sv.begin();
+
+    assert!(matches!(sv.try_get(end), Err(IndexError::OutOfBounds)));
+    assert!(matches!(sv.try_get(other.begin()), Err(IndexError::TagMismatch)));
+    sv.pop();
+
+    let error: Box<dyn std::error::Error> = Box::new(sv.try_get(first).unwrap_err());
+    assert_eq!(error.to_string(), "Index invalidated by a change on nicole::SafeVec");
+}
+
+#[test]
+#[should_panic(expected = "Index invalidated by a change on nicole::SafeVec")]
+fn indexing_with_an_invalidated_index_panics_with_the_error_message()
+{
+    let mut sv: SafeVec<i32> = (0..3).collect();
+    let first = sv.begin();
+    sv.pop();
+    let _ = sv[first];
+}
+
+#[test]
+#[should_panic(expected = "Index out of bounds on nicole::SafeVec")]
+fn removing_past_end_panics_with_the_error_message()
+{
+    let mut sv: SafeVec<i32> = (0..3).collect();
+    sv.remove(sv.past_end());
+}
+
+#[test]
+#[should_panic(expected = "Index from another collection on nicole::SafeVec")]
+fn swapping_a_foreign_index_panics_with_the_error_message()
+{
+    let a: SafeVec<i32> = (0..3).collect();
+    let mut b: SafeVec<i32> = (0..3).collect();
+    b.swap_indices(a.begin(), b.begin());
+}
+
+#[test]
+#[should_panic(expected = "Index out of bounds on nicole::SafeVec")]
+fn inserting_past_end_panics_with_the_error_message()
+{
+    let mut sv: SafeVec<i32> = (0..3).collect();
+    let mut at = sv.rend();
+    sv.decrement(&mut at);
+    sv.insert(at, 0);
+}
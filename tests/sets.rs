@@ -0,0 +1,148 @@
+use std::collections::BTreeSet;
+
+use nicole::{IdSet, DenseSet};
+
+mod common;
+
+use common::{Rng, check_ordered};
+
+// Up to `count` ids below `bound`; the bounds vary, so that the word vectors differ in length.
+fn random_set(rng: &mut Rng, count: usize, bound: usize) -> BTreeSet<u32>
+{
+    let count = rng.below(count + 1);
+    (0..count).map(|_| rng.below(bound) as u32).collect()
+}
+
+fn id_set(model: &BTreeSet<u32>) -> IdSet<u32>
+{
+    model.iter().copied().collect()
+}
+
+fn dense_set(model: &BTreeSet<u32>) -> DenseSet<u32>
+{
+    model.iter().copied().collect()
+}
+
+fn check_id_set(set: &IdSet<u32>, model: &BTreeSet<u32>)
+{
+    check_ordered(set.iter(), set.len(), set.is_empty(), model);
+}
+
+fn check_dense_set(set: &DenseSet<u32>, model: &BTreeSet<u32>)
+{
+    assert_eq!(set.iter().copied().collect::<BTreeSet<_>>(), *model);
+    assert_eq!(set.iter().count(), model.len());
+    assert!(model.iter().all(|x| set.contains(x)));
+}
+
+#[test]
+fn id_set_algebra_matches_btree_set()
+{
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for round in 0..500 {
+        let bound = 1 + (round % 6) * 70;
+        let (a, b) = (random_set(&mut rng, 80, bound), random_set(&mut rng, 80, bound + 130));
+        let (x, y) = (id_set(&a), id_set(&b));
+
+        check_id_set(&(&x | &y), &(&a | &b));
+        check_id_set(&(&x & &y), &(&a & &b));
+        check_id_set(&(&x - &y), &(&a - &b));
+        check_id_set(&(&x ^ &y), &(&a ^ &b));
+        check_id_set(&(&y - &x), &(&b - &a));
+
+        let mut z = x.clone();
+        z |= &y;
+        check_id_set(&z, &(&a | &b));
+        z &= &x;
+        check_id_set(&z, &a);
+        z ^= &y;
+        check_id_set(&z, &(&a ^ &b));
+        z -= &x;
+        check_id_set(&z, &(&b - &a));
+
+        for (s, t, u, v) in [(&x, &y, &a, &b), (&y, &x, &b, &a)] {
+            assert_eq!(s.is_subset(t), u.is_subset(v));
+            assert_eq!(s.is_superset(t), u.is_superset(v));
+            assert_eq!(s.is_disjoint(t), u.is_disjoint(v));
+        }
+        assert!((&x & &y).is_subset(&x));
+        assert!((&x | &y).is_superset(&y));
+        assert!((&x - &y).is_disjoint(&y));
+    }
+}
+
+// Equality ignores trailing empty words, which removals and intersections leave behind.
+#[test]
+fn id_set_equality_ignores_storage()
+{
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..200 {
+        let (a, b) = (random_set(&mut rng, 40, 100), random_set(&mut rng, 40, 600));
+        let (x, y) = (id_set(&a), id_set(&b));
+
+        let mut z = x.clone();
+        z |= &y;
+        z -= &y;
+        assert_eq!(z, &x - &y);
+        assert_eq!(&x & &y, &y & &x);
+        assert_eq!(&x | &y, &y | &x);
+        assert_eq!(&x ^ &y, &(&x | &y) - &(&x & &y));
+
+        let mut w = id_set(&b);
+        for id in &b {
+            w.remove(id);
+        }
+        assert_eq!(w, IdSet::new());
+        assert!(w.is_subset(&IdSet::new()));
+    }
+}
+
+#[test]
+fn dense_set_algebra_matches_btree_set()
+{
+    let mut rng = Rng(0x1234_5678_9abc_def1);
+    for round in 0..500 {
+        let bound = 1 + (round % 6) * 70;
+        let (a, b) = (random_set(&mut rng, 80, bound), random_set(&mut rng, 80, bound + 130));
+        let (x, y) = (dense_set(&a), dense_set(&b));
+
+        check_dense_set(&(&x | &y), &(&a | &b));
+        check_dense_set(&(&x & &y), &(&a & &b));
+        check_dense_set(&(&x - &y), &(&a - &b));
+        check_dense_set(&(&x ^ &y), &(&a ^ &b));
+        check_dense_set(&(&y - &x), &(&b - &a));
+
+        let mut z = x.clone();
+        z |= &y;
+        check_dense_set(&z, &(&a | &b));
+        z &= &x;
+        check_dense_set(&z, &a);
+        z ^= &y;
+        check_dense_set(&z, &(&a ^ &b));
+        z -= &x;
+        check_dense_set(&z, &(&b - &a));
+
+        for (s, t, u, v) in [(&x, &y, &a, &b), (&y, &x, &b, &a)] {
+            assert_eq!(s.is_subset(t), u.is_subset(v));
+            assert_eq!(s.is_superset(t), u.is_superset(v));
+            assert_eq!(s.is_disjoint(t), u.is_disjoint(v));
+        }
+    }
+}
+
+#[test]
+fn id_set_holds_the_highest_id()
+{
+    let mut set: IdSet<u8> = IdSet::new();
+    assert!(set.insert(254));
+    assert_eq!(set.max(), 254);
+    assert_eq!(set.iter().collect::<Vec<_>>(), [254]);
+}
+
+#[test]
+#[should_panic(expected = "Null id on nicole::IdSet")]
+fn id_set_rejects_null()
+{
+    let mut set: IdSet<u8> = IdSet::new();
+    set.insert(255);
+}
@@ -0,0 +1,157 @@
+use std::collections::BTreeSet;
+
+use nicole::{IdLike, SparseIdSet};
+
+mod common;
+
+use common::{Rng, check_ordered};
+
+// Ids from one of several layouts: dense, spread out, a few far-apart clusters, and both ends of the id
+// space; `usize::MAX` itself is null, so the largest id is `usize::MAX - 1`.
+fn random_id(rng: &mut Rng, layout: usize) -> usize
+{
+    match layout % 4 {
+        0 => rng.below(300),
+        1 => rng.below(5000),
+        2 => rng.below(3) * 1_000_000_000 + rng.below(200),
+        _ if rng.below(2) == 0 => rng.below(100),
+        _ => usize::MAX - 1 - rng.below(100)
+    }
+}
+
+fn check(set: &SparseIdSet<usize>, model: &BTreeSet<usize>)
+{
+    check_ordered(set.iter(), set.len(), set.is_empty(), model);
+}
+
+fn or_null(id: Option<&usize>) -> usize
+{
+    id.copied().unwrap_or_else(usize::null)
+}
+
+#[test]
+fn inserts_and_removes_match_btree_set()
+{
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for round in 0..200 {
+        let mut set = SparseIdSet::new();
+        let mut model = BTreeSet::new();
+
+        for _ in 0..300 {
+            let id = random_id(&mut rng, round);
+            if rng.below(3) != 0 {
+                assert_eq!(set.insert(id), model.insert(id));
+            }
+            else {
+                assert_eq!(set.remove(&id), model.remove(&id));
+            }
+            assert_eq!(set.contains(&id), model.contains(&id));
+        }
+        check(&set, &model);
+        assert_eq!(set.clone().into_iter().collect::<Vec<_>>(), set.iter().collect::<Vec<_>>());
+
+        // Emptying prunes the trie; refilling must still work.
+        for id in &model {
+            assert!(set.remove(id));
+        }
+        check(&set, &BTreeSet::new());
+        assert!(set.min().is_null() && set.max().is_null());
+        set.extend(model.iter().copied());
+        check(&set, &model);
+    }
+}
+
+#[test]
+fn ordered_queries_match_btree_set()
+{
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for round in 0..200 {
+        let model: BTreeSet<usize> = (0..rng.below(300)).map(|_| random_id(&mut rng, round)).collect();
+        let set: SparseIdSet<usize> = model.iter().copied().collect();
+
+        assert_eq!(set.min(), or_null(model.iter().next()));
+        assert_eq!(set.max(), or_null(model.iter().next_back()));
+
+        for _ in 0..200 {
+            let (a, b) = (random_id(&mut rng, round), random_id(&mut rng, round));
+            let (lo, hi) = (a.min(b), a.max(b));
+
+            assert_eq!(set.next_after(a), or_null(model.range(a + 1..).next()));
+            assert_eq!(set.prev_before(a), or_null(model.range(..a).next_back()));
+            assert_eq!(set.range(lo..hi).collect::<Vec<_>>(), model.range(lo..hi).copied().collect::<Vec<_>>());
+            assert_eq!(set.range(lo..=hi).collect::<Vec<_>>(), model.range(lo..=hi).copied().collect::<Vec<_>>());
+            assert_eq!(set.count_range(lo..hi), model.range(lo..hi).count());
+            assert_eq!(set.count_range(lo..=hi), model.range(lo..=hi).count());
+            assert_eq!(set.count_range(..hi), model.range(..hi).count());
+            assert_eq!(set.count_range(lo..), model.range(lo..).count());
+        }
+        assert_eq!(set.count_range(..), model.len());
+    }
+}
+
+#[test]
+fn ids_at_the_top_of_the_id_space()
+{
+    let top = usize::MAX - 1;
+    let mut set = SparseIdSet::new();
+    assert!(set.insert(top));
+    assert!(set.insert(top - 64));
+    assert!(set.insert(0));
+
+    assert_eq!(set.iter().collect::<Vec<_>>(), [0, top - 64, top]);
+    assert_eq!(set.max(), top);
+    assert!(set.next_after(top).is_null());
+    assert!(set.next_after(usize::null()).is_null());
+    assert_eq!(set.next_after(0), top - 64);
+    assert_eq!(set.prev_before(usize::null()), top);
+    assert_eq!(set.prev_before(top), top - 64);
+    assert_eq!(set.count_range(top - 64..), 2);
+    assert_eq!(set.count_range(..=top), 3);
+    assert_eq!(set.range(1..).collect::<Vec<_>>(), [top - 64, top]);
+
+    assert!(set.remove(&top));
+    assert_eq!(set.max(), top - 64);
+    assert!(set.remove(&(top - 64)));
+    assert_eq!(set.max(), 0);
+}
+
+#[test]
+fn algebra_matches_btree_set()
+{
+    let mut rng = Rng(0x1234_5678_9abc_def1);
+    for round in 0..200 {
+        let a: BTreeSet<usize> = (0..rng.below(200)).map(|_| random_id(&mut rng, round)).collect();
+        let b: BTreeSet<usize> = (0..rng.below(200)).map(|_| {
+            let layout = round + rng.below(2);
+            random_id(&mut rng, layout)
+        }).collect();
+        let x: SparseIdSet<usize> = a.iter().copied().collect();
+        let y: SparseIdSet<usize> = b.iter().copied().collect();
+
+        check(&(&x | &y), &(&a | &b));
+        check(&(&x & &y), &(&a & &b));
+        check(&(&x - &y), &(&a - &b));
+        check(&(&x ^ &y), &(&a ^ &b));
+
+        let mut z = x.clone();
+        z &= &y;
+        assert_eq!(z, &x & &y);
+        z ^= &z.clone();
+        assert!(z.is_empty());
+        assert_eq!(z, SparseIdSet::new());
+
+        for (s, t, u, v) in [(&x, &y, &a, &b), (&y, &x, &b, &a)] {
+            assert_eq!(s.is_subset(t), u.is_subset(v));
+            assert_eq!(s.is_superset(t), u.is_superset(v));
+            assert_eq!(s.is_disjoint(t), u.is_disjoint(v));
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "Null id on nicole::SparseIdSet")]
+fn sparse_set_rejects_null()
+{
+    let mut set: SparseIdSet<u8> = SparseIdSet::new();
+    set.insert(255);
+}
@@ -0,0 +1,110 @@
+use std::{fmt::Debug, num::{NonZeroU8, NonZeroU16}};
+
+use nicole::{
+    IdLike, NonMaxU8, NonMaxU16, ForwardIndex, BackwardIndex, RandomAccessIndex,
+    typedvec::TypedVec
+};
+
+fn full<K>() -> TypedVec<K, usize>
+    where K: IdLike + Debug
+{
+    let mut vec = TypedVec::new();
+    let mut i = 0;
+    while K::try_from_index(i + 1).is_some() {
+        assert_eq!(vec.push(i), K::from_index(i));
+        i += 1;
+    }
+    vec
+}
+
+// `past_end()` and `rend()` of a full vector are distinct keys, and moving still saturates at them.
+fn check_full<K>(len: usize)
+    where K: IdLike + Debug
+{
+    let vec = full::<K>();
+    assert_eq!(vec.len(), len);
+
+    let (end, rend) = (vec.past_end(), vec.rend());
+    assert_ne!(end, rend);
+    assert!(rend.is_null());
+    assert!(!end.is_null());
+
+    assert_eq!(vec.next(vec.rbegin()), end);
+    assert_eq!(vec.next(end), end);
+    assert_eq!(vec.prev(end), vec.rbegin());
+    assert_eq!(vec.prev(vec.begin()), rend);
+    assert_eq!(vec.prev(rend), rend);
+    assert_eq!(vec.next(rend), vec.begin());
+    assert_eq!(vec.distance(&rend, &end), len as isize + 1);
+
+    let mut index = vec.begin();
+    let mut count = 0;
+    while index != end {
+        assert_eq!(vec[index], count);
+        vec.increment(&mut index);
+        count += 1;
+    }
+    assert_eq!(count, len);
+}
+
+#[test]
+fn try_from_index_never_yields_null()
+{
+    assert_eq!(u8::try_from_index(254), Some(254));
+    assert_eq!(u8::try_from_index(255), None);
+    assert_eq!(u16::try_from_index(usize::from(u16::MAX)), None);
+    assert_eq!(usize::try_from_index(usize::MAX), None);
+    assert_eq!(NonZeroU8::try_from_index(253), NonZeroU8::new(254));
+    assert_eq!(NonZeroU8::try_from_index(254), None);
+    assert_eq!(NonMaxU8::try_from_index(253), NonMaxU8::new(253));
+    assert_eq!(NonMaxU8::try_from_index(254), None);
+
+    // `Option` ids have `None` as null, so the null of the integer is a key of its own.
+    assert_eq!(<Option<NonZeroU8>>::try_from_index(254), Some(NonZeroU8::new(255)));
+    assert_eq!(<Option<NonMaxU8>>::try_from_index(254), Some(NonMaxU8::new(254)));
+    assert_eq!(<Option<NonMaxU8>>::try_from_index(255), None);
+
+    for i in 0..=usize::from(u16::MAX) {
+        assert!(u16::try_from_index(i).is_none_or(|x| !x.is_null()));
+        assert!(NonZeroU16::try_from_index(i).is_none_or(|x| !x.is_null()));
+        assert!(NonMaxU16::try_from_index(i).is_none_or(|x| !x.is_null()));
+        assert!(<Option<NonMaxU16>>::try_from_index(i).is_none_or(|x| !x.is_null()));
+    }
+}
+
+#[test]
+fn full_vectors_keep_distinct_sentinels()
+{
+    check_full::<u8>(254);
+    check_full::<NonZeroU8>(253);
+    check_full::<NonMaxU8>(253);
+    check_full::<Option<NonZeroU8>>(254);
+    check_full::<Option<NonMaxU8>>(254);
+}
+
+#[test]
+fn empty_vector_sentinels()
+{
+    let vec: TypedVec<u8, ()> = TypedVec::new();
+    assert_eq!(vec.begin(), vec.past_end());
+    assert!(vec.rbegin().is_null());
+    assert_eq!(vec.next(vec.rend()), vec.past_end());
+}
+
+#[test]
+#[should_panic(expected = "Out of keys for nicole::TypedVec")]
+fn push_past_the_last_key_panics()
+{
+    let mut vec = full::<u8>();
+    vec.push(254);
+}
+
+#[test]
+#[should_panic(expected = "Out of keys for nicole::TypedVec")]
+fn insert_past_the_last_key_panics()
+{
+    let mut vec: TypedVec<u8, usize> = TypedVec::new();
+    vec.insert(253, 0);
+    assert_eq!(vec.len(), 254);
+    vec.insert(254, 0);
+}
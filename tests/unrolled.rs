@@ -0,0 +1,116 @@
+use nicole::{UnrolledVecList, ForwardIndex, BackwardIndex, IndexExt, unrolled::UnrolledIndex};
+
+mod common;
+
+use common::Rng;
+
+// `model` holds the index and value of every element, in list order.
+fn check(list: &UnrolledVecList<u64>, model: &[(UnrolledIndex, u64)])
+{
+    assert_eq!(list.len(), model.len());
+
+    let mut idx = list.begin();
+    for &(expected, value) in model {
+        assert_eq!(idx, expected);
+        assert_eq!(list[expected], value);
+        list.increment(&mut idx);
+    }
+    assert_eq!(idx, list.past_end());
+
+    let mut idx = list.rbegin();
+    for &(expected, _) in model.iter().rev() {
+        assert_eq!(idx, expected);
+        list.decrement(&mut idx);
+    }
+    assert_eq!(idx, list.rend());
+}
+
+// Inserts before `model[pos]` (or at the back); the new element is the one just before that.
+fn insert_at(list: &mut UnrolledVecList<u64>, model: &mut Vec<(UnrolledIndex, u64)>, pos: usize, value: u64)
+{
+    let before = if pos == model.len() { list.past_end() } else { model[pos].0 };
+    list.insert(before, value);
+    model.insert(pos, (list.prev(before), value));
+}
+
+#[test]
+fn indices_survive_splits()
+{
+    let mut list = UnrolledVecList::new();
+    let mut model = Vec::new();
+
+    // Inserting in one spot keeps splitting the same chunk.
+    for value in 0..300 {
+        let pos = model.len() / 2;
+        insert_at(&mut list, &mut model, pos, value);
+    }
+    check(&list, &model);
+
+    for value in 300..400 {
+        insert_at(&mut list, &mut model, 0, value);
+    }
+    check(&list, &model);
+}
+
+#[test]
+fn indices_survive_merges()
+{
+    let mut list = UnrolledVecList::new();
+    let mut model = Vec::new();
+    for value in 0..400 {
+        list.push_back(value);
+        model.push((list.rbegin(), value));
+    }
+
+    // Thinning out every chunk makes neighbours merge; then the middle is emptied out.
+    let mut i = 0;
+    while i < model.len() {
+        list.remove(model.remove(i).0);
+        i += 2;
+    }
+    check(&list, &model);
+
+    while model.len() > 10 {
+        let pos = model.len() / 2;
+        assert_eq!(list.remove(model[pos].0), model[pos].1);
+        model.remove(pos);
+    }
+    check(&list, &model);
+}
+
+#[test]
+fn random_edits_keep_indices_valid()
+{
+    let mut rng = Rng(0x4528_21e6_38d0_1377);
+    let mut list = UnrolledVecList::new();
+    let mut model: Vec<(UnrolledIndex, u64)> = Vec::new();
+    let mut removed = Vec::new();
+
+    for round in 0..4000 {
+        // Grow for a while, then shrink, so that chunks both split and merge.
+        let grow = (round / 500) % 2 == 0;
+        let insert = if grow { rng.below(3) != 0 } else { rng.below(3) == 0 };
+        if insert || model.is_empty() {
+            let pos = rng.below(model.len() + 1);
+            let value = rng.next();
+            insert_at(&mut list, &mut model, pos, value);
+        }
+        else {
+            let (idx, value) = model.remove(rng.below(model.len()));
+            assert_eq!(list.remove(idx), value);
+            removed.push(idx);
+        }
+
+        if round % 97 == 0 {
+            check(&list, &model);
+        }
+    }
+    check(&list, &model);
+
+    // Handles are recycled, so only those not reused since must be invalid.
+    for idx in removed {
+        if !model.iter().any(|&(x, _)| x == idx) {
+            assert!(!list.valid(&idx));
+        }
+    }
+}
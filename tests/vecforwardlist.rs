@@ -0,0 +1,92 @@
+use nicole::{VecForwardList, ForwardIndex};
+
+mod common;
+
+use common::Rng;
+
+fn contents(list: &VecForwardList<u64>) -> Vec<u64>
+{
+    list.iter().copied().collect()
+}
+
+#[test]
+fn before_begin_edits_the_front()
+{
+    let mut list = VecForwardList::new();
+    list.insert_after(list.before_begin(), 2);
+    list.insert_after(list.before_begin(), 1);
+    assert_eq!(list.next(list.before_begin()), list.begin());
+    assert_eq!(contents(&list), [1, 2]);
+    assert_eq!(list.back(), Some(&2));
+
+    assert_eq!(list.remove_after(list.before_begin()), 1);
+    assert_eq!(list.front(), Some(&2));
+    assert_eq!(list.remove_after(list.before_begin()), 2);
+    assert!(list.is_empty());
+    assert_eq!(list.begin(), list.past_end());
+
+    // With the list emptied through the front, the tail is the head again.
+    list.push_back(3);
+    assert_eq!(list.front(), Some(&3));
+    assert_eq!(list.back(), Some(&3));
+}
+
+#[test]
+fn removing_the_last_element_moves_the_tail_back()
+{
+    let mut list: VecForwardList<u64> = (0..3).collect();
+
+    let second = list.next(list.begin());
+    assert_eq!(list.remove_after(second), 2);
+    assert_eq!(list.back(), Some(&1));
+
+    list.push_back(5);
+    assert_eq!(contents(&list), [0, 1, 5]);
+    assert_eq!(list.back(), Some(&5));
+
+    // Inserting after the tail moves it forward, like `push_back`.
+    let last = list.next(second);
+    list.insert_after(last, 6);
+    assert_eq!(list.back(), Some(&6));
+    list.push_back(7);
+    assert_eq!(contents(&list), [0, 1, 5, 6, 7]);
+}
+
+#[test]
+#[should_panic(expected = "Nothing to remove on nicole::VecForwardList")]
+fn remove_after_the_last_element_panics()
+{
+    let mut list: VecForwardList<u64> = (0..2).collect();
+    let last = list.next(list.begin());
+    list.remove_after(last);
+}
+
+#[test]
+fn edits_match_a_vec()
+{
+    let mut rng = Rng(0x1f83_d9ab_fb41_bd6b);
+    let mut list = VecForwardList::new();
+    let mut model: Vec<u64> = Vec::new();
+
+    for _ in 0..3000 {
+        // `pos` is the number of elements before the edit; 0 goes through `before_begin`.
+        let pos = rng.below(model.len() + 1);
+        let mut prev = list.before_begin();
+        list.move_forward(&mut prev, pos);
+
+        if rng.below(2) == 0 && pos < model.len() {
+            assert_eq!(list.remove_after(prev), model.remove(pos));
+        }
+        else {
+            let value = rng.next();
+            list.insert_after(prev, value);
+            model.insert(pos, value);
+        }
+
+        assert_eq!(list.len(), model.len());
+        assert_eq!(list.front(), model.first());
+        assert_eq!(list.back(), model.last());
+    }
+
+    assert_eq!(contents(&list), model);
+}
@@ -0,0 +1,291 @@
+use std::cmp::Ordering;
+
+use nicole::{VecList, ForwardIndex, BackwardIndex, veclist::VecListIndex};
+
+mod common;
+
+use common::Rng;
+
+#[test]
+fn clear_keeps_the_list_usable()
+{
+    let mut list = VecList::new();
+    list.push_back(1);
+    list.push_back(2);
+    list.clear();
+
+    assert_eq!(list.len(), 0);
+    assert_eq!(list.iter().count(), 0);
+
+    list.push_back(3);
+    list.push_front(0);
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 3]);
+}
+
+// Inserts `value` before `model[pos]` (or at the back), keeping `model` in list order.
+fn insert_at(list: &mut VecList<usize>, model: &mut Vec<VecListIndex>, pos: usize, value: usize)
+{
+    let next = if pos == model.len() { list.past_end() } else { model[pos] };
+    list.insert(next, value);
+
+    let mut index = next;
+    list.decrement(&mut index);
+    model.insert(pos, index);
+}
+
+fn check_order(list: &VecList<usize>, model: &[VecListIndex])
+{
+    for (i, &a) in model.iter().enumerate() {
+        for (j, &b) in model.iter().enumerate() {
+            assert_eq!(list.cmp_order(a, b), i.cmp(&j));
+        }
+    }
+}
+
+#[test]
+fn order_labels_follow_the_list_order()
+{
+    let mut rng = Rng(0x2545_F491_4F6C_DD1D);
+    let mut list = VecList::new();
+    list.enable_order();
+    let mut model = Vec::new();
+
+    for value in 0..300 {
+        let pos = rng.below(model.len() + 1);
+        insert_at(&mut list, &mut model, pos, value);
+
+        if rng.below(4) == 0 {
+            let pos = rng.below(model.len());
+            list.remove(model.remove(pos));
+        }
+    }
+    check_order(&list, &model);
+
+    list.reverse();
+    model.reverse();
+    check_order(&list, &model);
+}
+
+#[test]
+fn order_labels_relabel_under_clustered_inserts()
+{
+    let mut list = VecList::new();
+    list.enable_order();
+    let mut model = Vec::new();
+
+    insert_at(&mut list, &mut model, 0, 0);
+    insert_at(&mut list, &mut model, 1, 1);
+
+    // Always inserting right after the first element halves the same label gap every time, which
+    // runs out after 64 inserts without relabeling.
+    for value in 2..2000 {
+        insert_at(&mut list, &mut model, 1, value);
+    }
+    check_order(&list, &model[..200]);
+    check_order(&list, &model[1800..]);
+
+    // And right before the last one.
+    let len = model.len();
+    for value in 2000..2500 {
+        insert_at(&mut list, &mut model, len - 1 + (value - 2000), value);
+    }
+    for window in model.windows(2) {
+        assert_eq!(list.cmp_order(window[0], window[1]), Ordering::Less);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Invalid index on nicole::VecList")]
+fn cmp_order_rejects_removed_indices()
+{
+    let mut list: VecList<usize> = (0..3).collect();
+    list.enable_order();
+    let removed = list.begin();
+    let last = list.rbegin();
+    list.remove(removed);
+    list.cmp_order(removed, last);
+}
+
+#[test]
+#[should_panic(expected = "Invalid index on nicole::VecList")]
+fn cmp_order_rejects_sentinels()
+{
+    let mut list: VecList<usize> = (0..3).collect();
+    list.enable_order();
+    list.cmp_order(list.begin(), list.past_end());
+}
+
+fn check_ranks(list: &VecList<usize>, model: &[VecListIndex])
+{
+    for (k, &index) in model.iter().enumerate() {
+        assert_eq!(list.nth(k), index);
+        assert_eq!(list.position(index), k);
+    }
+    assert_eq!(list.nth(model.len()), list.past_end());
+}
+
+#[test]
+fn ranks_follow_the_list_order()
+{
+    let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+    let mut list = VecList::new();
+    list.push_back(usize::MAX);
+    list.enable_ranks();
+    let mut model = vec![list.begin()];
+
+    for value in 0..400 {
+        let pos = rng.below(model.len() + 1);
+        insert_at(&mut list, &mut model, pos, value);
+
+        if rng.below(3) == 0 {
+            let pos = rng.below(model.len());
+            list.remove(model.remove(pos));
+        }
+    }
+    check_ranks(&list, &model);
+
+    list.sort();
+    model.sort_by_key(|&index| list[index]);
+    check_ranks(&list, &model);
+}
+
+#[test]
+fn ranked_movement_matches_stepping()
+{
+    let mut rng = Rng(0xD1B5_4A32_D192_ED03);
+    let mut list: VecList<usize> = (0..100).collect();
+    list.enable_ranks();
+    let model: Vec<_> = {
+        let mut index = list.begin();
+        (0..100).map(|_| { let ret = index; list.increment(&mut index); ret }).collect()
+    };
+
+    for _ in 0..500 {
+        let from = rng.below(model.len());
+        let distance = rng.below(150);
+
+        let mut index = model[from];
+        list.move_forward(&mut index, distance);
+        assert_eq!(index, model.get(from + distance).copied().unwrap_or_else(|| list.past_end()));
+
+        let mut index = model[from];
+        list.move_backward(&mut index, distance);
+        assert_eq!(index, if distance <= from { model[from - distance] } else { list.rend() });
+    }
+
+    // From the sentinels.
+    let mut index = list.rend();
+    list.move_forward(&mut index, 1);
+    assert_eq!(index, list.begin());
+    let mut index = list.past_end();
+    list.move_backward(&mut index, 100);
+    assert_eq!(index, list.begin());
+    list.move_forward(&mut index, usize::MAX);
+    assert_eq!(index, list.past_end());
+}
+
+#[test]
+fn into_iter_moves_elements_out()
+{
+    // Neither Clone nor Copy; the counter catches double drops and leaks.
+    #[allow(dead_code)]
+    struct Counted(std::rc::Rc<()>);
+
+    let counter = std::rc::Rc::new(());
+    let mut list = VecList::new();
+    for _ in 0..5 {
+        list.push_back(Counted(counter.clone()));
+    }
+    let index = list.begin();
+    list.remove(index);
+
+    let mut iter = list.into_iter();
+    let first = iter.next();
+    assert_eq!(std::rc::Rc::strong_count(&counter), 5);
+    drop(iter);
+    assert_eq!(std::rc::Rc::strong_count(&counter), 2);
+    drop(first);
+    assert_eq!(std::rc::Rc::strong_count(&counter), 1);
+}
+
+// `end()` kept its pre-0.5 meaning, the last element, under a deprecation.
+#[test]
+#[allow(deprecated)]
+fn deprecated_end_is_the_last_element()
+{
+    let list: VecList<i32> = (0..3).collect();
+    assert_eq!(list.end(), list.rbegin());
+    assert_eq!(list[list.end()], 2);
+    assert_ne!(list.end(), list.past_end());
+
+    let vec = vec![1, 2];
+    assert_eq!(BackwardIndex::end(&vec), 1);
+}
+
+// With `u16` links, the two largest values are the invalid and vacant markers, so the list holds
+// 65534 nodes, two of which are sentinels.
+#[test]
+fn narrow_links_fill_up_to_capacity()
+{
+    let mut list = VecList::<usize, u16>::compact();
+    for i in 0..65532 {
+        list.push_back(i);
+    }
+    assert_eq!(list.len(), 65532);
+
+    // The highest slot is one below the markers; it must still read back as an element.
+    let last = list.rbegin();
+    assert_eq!(list[last], 65531);
+    assert_eq!(list.next(last), list.past_end());
+    assert_eq!(list.next(list.past_end()), list.past_end());
+    assert_eq!(list.prev(list.begin()), list.rend());
+    assert!(list.iter().copied().eq(0..65532));
+
+    // Removed slots are reused, so a full list can still take as many elements as it gave back.
+    list.remove(last);
+    let first = list.begin();
+    list.remove(first);
+    list.push_front(0);
+    list.push_back(65531);
+    assert!(list.iter().copied().eq(0..65532));
+}
+
+#[test]
+#[should_panic(expected = "Link capacity exceeded on nicole::VecList")]
+fn narrow_links_panic_past_capacity()
+{
+    let mut list = VecList::<usize, u16>::compact();
+    for i in 0..65533 {
+        list.push_back(i);
+    }
+}
+
+#[test]
+fn u32_links_match_usize_links()
+{
+    let mut rng = Rng(0x3243_f6a8_885a_308d);
+    let mut narrow = VecList::<u64, u32>::compact();
+    let mut wide = VecList::<u64>::new();
+
+    for _ in 0..2000 {
+        let value = rng.next();
+        match rng.below(4) {
+            0 if !wide.is_empty() => assert_eq!(narrow.pop_front(), wide.pop_front()),
+            1 if !wide.is_empty() => assert_eq!(narrow.pop_back(), wide.pop_back()),
+            2 => { narrow.push_front(value); wide.push_front(value) },
+            _ => { narrow.push_back(value); wide.push_back(value) }
+        }
+        assert_eq!(narrow.len(), wide.len());
+    }
+
+    assert!(narrow.iter().eq(wide.iter()));
+
+    // Walk backwards too, which reads the `prev` links.
+    let (mut i, mut j) = (narrow.rbegin(), wide.rbegin());
+    while j != wide.rend() {
+        assert_eq!(narrow[i], wide[j]);
+        i = narrow.prev(i);
+        j = wide.prev(j);
+    }
+    assert_eq!(i, narrow.rend());
+}